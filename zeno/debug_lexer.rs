@@ -4,8 +4,11 @@ fn main() {
     let mut lexer = zeno::lexer::Lexer::new(input);
     println!("Debugging input: {}", input);
     loop {
-        let tok = lexer.next_token();
+        let (tok, err) = lexer.next_token();
         println!("Token: {:?}", tok);
+        if let Some(err) = err {
+            println!("  Error: {}", err);
+        }
         if matches!(tok, zeno::lexer::Token::Eof) {
             break;
         }
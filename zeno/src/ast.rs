@@ -1,6 +1,34 @@
+use crate::lexer::Position;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Program {
     pub statements: Vec<Statement>,
+    /// A trailing expression with no terminating semicolon, parsed in REPL
+    /// mode (`Parser::new_repl`) so an interactive shell can print its
+    /// value. Always `None` outside REPL mode; in REPL mode it's `None`
+    /// too unless the program's very last statement was an un-terminated
+    /// expression statement.
+    pub result: Option<Expr>,
+    /// The source span of each entry in `statements`, in the same order
+    /// (`statement_spans[i]` covers `statements[i]`). Kept as a parallel
+    /// array rather than a field on `Statement` itself, the same way the
+    /// parser already threads `current_pos`/`peek_pos` alongside
+    /// `current_token`/`peek_token`: it gives tooling (an LSP, a REPL
+    /// that wants to underline the statement that failed at runtime) a
+    /// precise source location without forcing every existing `Statement`
+    /// match arm across the interpreter, typeck, and codegen backends to
+    /// grow a `span` field and a matching `..` pattern.
+    pub statement_spans: Vec<SourceSpan>,
+}
+
+/// A source range, from the first token of a construct to its last.
+/// Courser-grained than `lexer::Span` (no byte offsets, since the parser
+/// only tracks line/column), but enough to point a diagnostic at "the
+/// statement starting here, ending there".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceSpan {
+    pub start: Position,
+    pub end: Position,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -11,8 +39,13 @@ pub enum Statement {
         mutable: bool,
         value_expr: Expr,
     },
+    /// `target = value_expr`, or a compound form (`target += value_expr`,
+    /// etc.) already desugared by the parser into a plain assignment whose
+    /// `value_expr` is `target OP rhs`. `target` is restricted by the
+    /// parser to an assignable form: a bare identifier or an index
+    /// expression (`arr[i]`), covering both `x = v` and `arr[i] = v`.
     Assignment {
-        name: String,
+        target: Expr,
         value_expr: Expr,
     },
     ExprStatement {
@@ -34,7 +67,11 @@ pub enum Statement {
     For {
         initializer: Option<Box<Statement>>,
         condition: Option<Expr>,
-        increment: Option<Expr>,
+        /// Parsed the same way as `initializer` (a full statement, not a
+        /// bare expression): the canonical increment clause is an
+        /// assignment (`i = i + 1`, or `i += 1`), which only exists as a
+        /// `Statement::Assignment`, not an `Expr` variant.
+        increment: Option<Box<Statement>>,
         body_block: Block,
     },
     Print {
@@ -43,11 +80,27 @@ pub enum Statement {
     },
     Break,
     Continue,
+    FnDecl {
+        name: String,
+        params: Vec<(String, String)>,
+        return_type: Option<String>,
+        body: Block,
+    },
+    Return {
+        expr: Option<Expr>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Block {
     pub statements: Vec<Statement>,
+    /// A trailing expression with no terminating semicolon, the block's
+    /// implicit value -- mirrors `Program::result`, but at block scope so a
+    /// function body's last bare expression can serve as its return value
+    /// without a `return` keyword (`fn add(a: int, b: int): int { a + b }`).
+    /// Other block consumers (`if`/`while`/`for`/`loop` bodies) parse it the
+    /// same way but simply don't read it back out.
+    pub result: Option<Expr>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -67,9 +120,32 @@ pub enum Expr {
         expr: Box<Expr>,
     },
     Call {
-        callee: String,
+        callee: Box<Expr>,
         args: Vec<Expr>,
     },
+    /// Dot access (`obj.field`), also the callee shape for method calls like
+    /// `obj.method(arg)`, which parse as `Expr::Call` whose `callee` is a
+    /// `Member` expression.
+    Member {
+        target: Box<Expr>,
+        field: String,
+    },
+    ArrayLiteral(Vec<Expr>),
+    /// A map literal, `{ key: expr, ... }`, with bare-identifier keys in
+    /// declaration order (no `HashMap` here, so printing and iteration stay
+    /// deterministic -- mirrors the `Vec<(String, String)>` shape already
+    /// used for `Function`/`FnDecl` parameters).
+    Map(Vec<(String, Expr)>),
+    Index {
+        target: Box<Expr>,
+        index: Box<Expr>,
+    },
+    /// A boxed binary operator produced by the backslash prefix (e.g. `\+`),
+    /// equivalent to the anonymous function `fn(a, b) { a <op> b }`. Not yet
+    /// invocable through `Expr::Call` -- named functions are still resolved
+    /// by looking up an `Expr::Identifier` callee by name, not by evaluating
+    /// the callee as a value.
+    OperatorFn(BinaryOperator),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -0,0 +1,257 @@
+//! Implementation of the `zeno bench` subcommand. Times lexing, parsing,
+//! and codegen independently over a corpus of `.zeno` files, in the style
+//! of syn's `benches/rust.rs`, so a regression in one front-end stage
+//! doesn't get hidden by the others.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use zeno::ast::Program;
+use zeno::generator;
+use zeno::lexer::Lexer;
+use zeno::parser::{ParseError, Parser};
+
+pub struct FileTiming {
+    pub path: PathBuf,
+    pub bytes: u64,
+    pub lines: u64,
+    pub lexing: Duration,
+    pub parsing: Duration,
+    pub codegen: Duration,
+}
+
+/// Aggregated timing for one phase across the whole corpus. `total` is
+/// the sum, over all files, of each file's best-of-`iterations` time
+/// (warmed up first) — not the sum of every iteration run, which would
+/// just measure how many iterations we chose to do.
+pub struct PhaseTotals {
+    pub total: Duration,
+    pub bytes: u64,
+    pub lines: u64,
+}
+
+impl PhaseTotals {
+    fn bytes_per_sec(&self) -> f64 {
+        let secs = self.total.as_secs_f64();
+        if secs == 0.0 { 0.0 } else { self.bytes as f64 / secs }
+    }
+
+    fn lines_per_sec(&self) -> f64 {
+        let secs = self.total.as_secs_f64();
+        if secs == 0.0 { 0.0 } else { self.lines as f64 / secs }
+    }
+}
+
+pub struct BenchReport {
+    pub files: Vec<FileTiming>,
+    pub lexing: PhaseTotals,
+    pub parsing: PhaseTotals,
+    pub codegen: PhaseTotals,
+}
+
+/// Walks `dir` for `.zeno` files and times lexing, parsing, and codegen
+/// for each: `warmup` untimed iterations to let things settle, then the
+/// minimum wall time observed over `iterations` timed ones. A file that
+/// fails to parse is still lexed and reported, with its codegen time
+/// recorded as zero rather than aborting the whole run.
+pub fn run(dir: &Path, iterations: usize, warmup: usize) -> io::Result<BenchReport> {
+    let mut paths = Vec::new();
+    find_zeno_files(dir, &mut paths)?;
+    paths.sort();
+
+    let mut files = Vec::new();
+    let mut lexing_total = Duration::ZERO;
+    let mut parsing_total = Duration::ZERO;
+    let mut codegen_total = Duration::ZERO;
+    let mut total_bytes = 0u64;
+    let mut total_lines = 0u64;
+
+    for path in paths {
+        let source = fs::read_to_string(&path)?;
+        let bytes = source.len() as u64;
+        let lines = source.lines().count() as u64;
+
+        for _ in 0..warmup {
+            drain_lexer(&source);
+        }
+        let lexing = time_min(iterations, || {
+            drain_lexer(&source);
+        });
+
+        for _ in 0..warmup {
+            let _ = parse(&source);
+        }
+        let parsing = time_min(iterations, || {
+            let _ = parse(&source);
+        });
+
+        total_bytes += bytes;
+        total_lines += lines;
+        lexing_total += lexing;
+        parsing_total += parsing;
+
+        // A single untimed parse, just to get an AST to feed codegen —
+        // reused across all codegen iterations so parse time doesn't
+        // leak into the codegen measurement.
+        let codegen = match parse(&source) {
+            Ok(ast) => {
+                for _ in 0..warmup {
+                    let _ = generator::generate(&ast);
+                }
+                let codegen = time_min(iterations, || {
+                    let _ = generator::generate(&ast);
+                });
+                codegen_total += codegen;
+                codegen
+            }
+            Err(_) => Duration::ZERO,
+        };
+
+        files.push(FileTiming { path, bytes, lines, lexing, parsing, codegen });
+    }
+
+    Ok(BenchReport {
+        files,
+        lexing: PhaseTotals { total: lexing_total, bytes: total_bytes, lines: total_lines },
+        parsing: PhaseTotals { total: parsing_total, bytes: total_bytes, lines: total_lines },
+        codegen: PhaseTotals { total: codegen_total, bytes: total_bytes, lines: total_lines },
+    })
+}
+
+fn drain_lexer(source: &str) {
+    let lexer = Lexer::new(source);
+    let mut count = 0u64;
+    for token in lexer {
+        count += 1;
+        std::hint::black_box(&token);
+    }
+    std::hint::black_box(count);
+}
+
+fn parse(source: &str) -> Result<Program, Vec<ParseError>> {
+    let lexer = Lexer::new(source);
+    let mut parser = Parser::new(lexer);
+    parser.parse_program()
+}
+
+fn time_min(iterations: usize, mut f: impl FnMut()) -> Duration {
+    let mut best = None;
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        f();
+        let elapsed = start.elapsed();
+        best = Some(match best {
+            Some(current_best) if current_best < elapsed => current_best,
+            _ => elapsed,
+        });
+    }
+    best.unwrap_or(Duration::ZERO)
+}
+
+fn find_zeno_files(dir: &Path, out: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            find_zeno_files(&path, out)?;
+        } else if path.extension().and_then(|ext| ext.to_str()) == Some("zeno") {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct JsonPhase {
+    total_secs: f64,
+    bytes_per_sec: f64,
+    lines_per_sec: f64,
+}
+
+#[derive(Serialize)]
+struct JsonFileTiming {
+    path: String,
+    bytes: u64,
+    lines: u64,
+    lexing_secs: f64,
+    parsing_secs: f64,
+    codegen_secs: f64,
+}
+
+#[derive(Serialize)]
+struct JsonReport {
+    lexing: JsonPhase,
+    parsing: JsonPhase,
+    codegen: JsonPhase,
+    files: Vec<JsonFileTiming>,
+}
+
+/// Prints the bench report: a per-phase throughput summary, the slowest
+/// `top` files per phase, and (with `json`) a machine-readable dump
+/// suitable for tracking timings across runs.
+pub fn print_report(report: &BenchReport, top: usize, json: bool) {
+    if json {
+        let json_report = JsonReport {
+            lexing: to_json_phase(&report.lexing),
+            parsing: to_json_phase(&report.parsing),
+            codegen: to_json_phase(&report.codegen),
+            files: report
+                .files
+                .iter()
+                .map(|f| JsonFileTiming {
+                    path: f.path.display().to_string(),
+                    bytes: f.bytes,
+                    lines: f.lines,
+                    lexing_secs: f.lexing.as_secs_f64(),
+                    parsing_secs: f.parsing.as_secs_f64(),
+                    codegen_secs: f.codegen.as_secs_f64(),
+                })
+                .collect(),
+        };
+        match serde_json::to_string_pretty(&json_report) {
+            Ok(text) => println!("{text}"),
+            Err(e) => eprintln!("Failed to serialize bench report: {e}"),
+        }
+        return;
+    }
+
+    println!("{:<10} {:>12} {:>14} {:>14}", "phase", "total", "bytes/sec", "lines/sec");
+    print_phase_row("lexing", &report.lexing);
+    print_phase_row("parsing", &report.parsing);
+    print_phase_row("codegen", &report.codegen);
+
+    print_slowest("lexing", &report.files, top, |f| f.lexing);
+    print_slowest("parsing", &report.files, top, |f| f.parsing);
+    print_slowest("codegen", &report.files, top, |f| f.codegen);
+}
+
+fn to_json_phase(totals: &PhaseTotals) -> JsonPhase {
+    JsonPhase {
+        total_secs: totals.total.as_secs_f64(),
+        bytes_per_sec: totals.bytes_per_sec(),
+        lines_per_sec: totals.lines_per_sec(),
+    }
+}
+
+fn print_phase_row(name: &str, totals: &PhaseTotals) {
+    println!(
+        "{:<10} {:>12?} {:>14.0} {:>14.0}",
+        name,
+        totals.total,
+        totals.bytes_per_sec(),
+        totals.lines_per_sec(),
+    );
+}
+
+fn print_slowest(phase_name: &str, files: &[FileTiming], top: usize, duration_of: impl Fn(&FileTiming) -> Duration) {
+    let mut ranked: Vec<&FileTiming> = files.iter().collect();
+    ranked.sort_by(|a, b| duration_of(b).cmp(&duration_of(a)));
+
+    println!("\nSlowest {phase_name} ({}):", ranked.len().min(top));
+    for file in ranked.into_iter().take(top) {
+        println!("  {:>10?}  {}", duration_of(file), file.path.display());
+    }
+}
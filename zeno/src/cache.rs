@@ -0,0 +1,122 @@
+//! Content-addressed cache for compiled Zeno programs. Keyed by a digest
+//! over everything that can affect the output, so the common
+//! edit-nothing/rebuild loop can copy a previous executable instead of
+//! re-running codegen and `rustc`.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use sha2::{Digest, Sha256};
+
+/// Bumped implicitly by the crate version: baked into every digest so
+/// upgrading zeno invalidates cache entries built by an older compiler
+/// instead of serving output it would no longer produce.
+const CACHE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Everything that affects a compiled Zeno program's output. All of this
+/// has to feed the digest, or a cache hit could serve stale or mismatched
+/// output — `executable_stem` matters because it leaks into the binary's
+/// own panic messages, not just its file name.
+pub struct CacheKey<'a> {
+    pub source: &'a str,
+    pub executable_stem: &'a str,
+    pub opt_level: &'a str,
+    pub ast_optimize_level: &'a str,
+}
+
+impl CacheKey<'_> {
+    pub fn digest(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.source.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(self.executable_stem.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(self.opt_level.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(self.ast_optimize_level.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(CACHE_VERSION.as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+/// One cached build: the generated Rust source and the compiled
+/// executable, both present on disk under a digest-named directory.
+pub struct Entry {
+    dir: PathBuf,
+}
+
+impl Entry {
+    pub fn rust_source_path(&self) -> PathBuf {
+        self.dir.join("source.rs")
+    }
+
+    pub fn executable_path(&self) -> PathBuf {
+        self.dir.join("executable")
+    }
+}
+
+/// A build cache rooted at `dir` (by default `$XDG_CACHE_HOME/zeno`),
+/// keyed by `CacheKey::digest`.
+pub struct BuildCache {
+    dir: PathBuf,
+}
+
+impl BuildCache {
+    pub fn new(dir: PathBuf) -> Self {
+        BuildCache { dir }
+    }
+
+    /// Resolves the default cache directory: `$XDG_CACHE_HOME/zeno`, or
+    /// `$HOME/.cache/zeno` if `XDG_CACHE_HOME` isn't set.
+    pub fn default_dir() -> PathBuf {
+        if let Ok(xdg) = std::env::var("XDG_CACHE_HOME") {
+            return PathBuf::from(xdg).join("zeno");
+        }
+        let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
+        PathBuf::from(home).join(".cache").join("zeno")
+    }
+
+    fn entry_dir(&self, digest: &str) -> PathBuf {
+        self.dir.join(digest)
+    }
+
+    /// Looks up `digest`, returning the entry only if both its generated
+    /// source and executable are actually present (a partially-written or
+    /// since-pruned entry is treated as a miss).
+    pub fn lookup(&self, digest: &str) -> Option<Entry> {
+        let entry = Entry { dir: self.entry_dir(digest) };
+        if entry.rust_source_path().is_file() && entry.executable_path().is_file() {
+            Some(entry)
+        } else {
+            None
+        }
+    }
+
+    /// Stores a freshly-built entry: the generated Rust source, the
+    /// compiled executable, and a sidecar manifest recording when it was
+    /// written and where the original Zeno source lived, so the cache can
+    /// later be pruned by age.
+    pub fn store(&self, digest: &str, rust_source: &str, executable_path: &Path, original_source_path: &Path) -> io::Result<Entry> {
+        let entry = Entry { dir: self.entry_dir(digest) };
+        fs::create_dir_all(&entry.dir)?;
+        fs::write(entry.rust_source_path(), rust_source)?;
+        fs::copy(executable_path, entry.executable_path())?;
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let manifest = format!(
+            "digest={}\noriginal_source_path={}\ntimestamp={}\n",
+            digest,
+            original_source_path.display(),
+            timestamp,
+        );
+        fs::write(entry.dir.join("manifest"), manifest)?;
+
+        Ok(entry)
+    }
+}
@@ -0,0 +1,79 @@
+//! Parses rustc's `--error-format=json` diagnostics and re-renders them
+//! against the originating Zeno source, using the generator's source map
+//! (see `zeno::generator::SpanMapping`) to translate a generated-Rust
+//! byte offset back to the Zeno statement that produced it.
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use zeno::generator::SpanMapping;
+
+#[derive(Deserialize, Debug)]
+struct RustcSpan {
+    byte_start: usize,
+    #[serde(default)]
+    is_primary: bool,
+}
+
+#[derive(Deserialize, Debug)]
+struct RustcDiagnostic {
+    message: String,
+    level: String,
+    #[serde(default)]
+    spans: Vec<RustcSpan>,
+}
+
+/// Parses each line of `rustc`'s `--error-format=json` stderr as a
+/// diagnostic, translates its primary span back to a Zeno statement via
+/// `source_map`, and prints a Zeno-facing rendering. A line that isn't
+/// valid diagnostic JSON (an internal compiler panic, say) is printed
+/// verbatim rather than silently dropped. Returns the number of
+/// `"error"`-level diagnostics seen.
+pub fn report(stderr: &str, source_map: &[SpanMapping], zeno_source_path: &Path) -> usize {
+    let mut error_count = 0;
+
+    for line in stderr.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<RustcDiagnostic>(line) {
+            Ok(diagnostic) => {
+                if diagnostic.level == "error" {
+                    error_count += 1;
+                }
+
+                let primary_span = diagnostic.spans.iter().find(|span| span.is_primary);
+                match primary_span.and_then(|span| locate(source_map, span.byte_start)) {
+                    Some((origin, approximate)) => {
+                        let note = if approximate { " (approximate location)" } else { "" };
+                        println!("{}: {}: {}", zeno_source_path.display(), diagnostic.level, diagnostic.message);
+                        println!("  --> {origin}{note}");
+                    }
+                    None => {
+                        println!("{}: {}: {}", zeno_source_path.display(), diagnostic.level, diagnostic.message);
+                    }
+                }
+            }
+            Err(_) => println!("{line}"),
+        }
+    }
+
+    error_count
+}
+
+/// Binary-searches `source_map` (sorted by `generated_start`) for the
+/// entry enclosing `byte_offset`, returning its origin description and
+/// whether the match is exact. Falls back to the nearest preceding entry,
+/// marked approximate, when `byte_offset` falls in generator-synthesized
+/// code (boilerplate between statements) with no mapped origin of its own.
+fn locate(source_map: &[SpanMapping], byte_offset: usize) -> Option<(String, bool)> {
+    let index = source_map.partition_point(|mapping| mapping.generated_start <= byte_offset);
+    if index == 0 {
+        return None;
+    }
+    let candidate = &source_map[index - 1];
+    let approximate = byte_offset >= candidate.generated_end;
+    Some((candidate.origin.clone(), approximate))
+}
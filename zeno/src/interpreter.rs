@@ -0,0 +1,703 @@
+use crate::ast::*;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::Write;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+    Array(Vec<Value>),
+    /// A map value, keyed by declaration-order bare identifiers -- mirrors
+    /// `Expr::Map`'s `Vec<(String, Expr)>` shape rather than a `HashMap`, so
+    /// printing stays deterministic.
+    Map(Vec<(String, Value)>),
+    /// A boxed binary operator produced by the `\op` prefix (e.g. `\+`).
+    /// Holdable and passable like any other value, but not yet callable --
+    /// `Expr::Call`'s callee is a bare name, not an arbitrary expression.
+    Function(BinaryOperator),
+    Unit,
+}
+
+fn binary_operator_symbol(op: &BinaryOperator) -> &'static str {
+    match op {
+        BinaryOperator::Plus => "+",
+        BinaryOperator::Minus => "-",
+        BinaryOperator::Multiply => "*",
+        BinaryOperator::Divide => "/",
+        BinaryOperator::Modulo => "%",
+        BinaryOperator::Eq => "==",
+        BinaryOperator::NotEq => "!=",
+        BinaryOperator::Lt => "<",
+        BinaryOperator::Lte => "<=",
+        BinaryOperator::Gt => ">",
+        BinaryOperator::Gte => ">=",
+        BinaryOperator::And => "&&",
+        BinaryOperator::Or => "||",
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(v) => write!(f, "{}", v),
+            Value::Float(v) => write!(f, "{}", v),
+            Value::Bool(v) => write!(f, "{}", v),
+            Value::Str(v) => write!(f, "{}", v),
+            Value::Array(elements) => {
+                write!(f, "[")?;
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                write!(f, "]")
+            }
+            Value::Map(entries) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", key, value)?;
+                }
+                write!(f, "}}")
+            }
+            Value::Function(op) => write!(f, "\\{}", binary_operator_symbol(op)),
+            Value::Unit => write!(f, "()"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterpretError(pub String);
+
+impl fmt::Display for InterpretError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Interpret Error: {}", self.0)
+    }
+}
+
+impl std::error::Error for InterpretError {}
+
+// Signal returned from statement execution so `break`/`continue`/`return` can
+// unwind through nested blocks without a panic.
+enum Signal {
+    Normal,
+    Break,
+    Continue,
+    Return(Value),
+}
+
+#[derive(Clone)]
+struct Function {
+    params: Vec<(String, String)>,
+    body: Block,
+}
+
+pub struct Interpreter<'out> {
+    scopes: Vec<HashMap<String, Value>>,
+    functions: HashMap<String, Function>,
+    out: &'out mut dyn Write,
+}
+
+impl<'out> Interpreter<'out> {
+    pub fn new(out: &'out mut dyn Write) -> Self {
+        Interpreter {
+            scopes: vec![HashMap::new()],
+            functions: HashMap::new(),
+            out,
+        }
+    }
+
+    pub fn run(&mut self, program: &Program) -> Result<(), InterpretError> {
+        for statement in &program.statements {
+            match self.exec_statement(statement)? {
+                Signal::Normal => {}
+                Signal::Return(_) => {
+                    return Err(InterpretError("'return' outside of a function".to_string()));
+                }
+                Signal::Break | Signal::Continue => {
+                    return Err(InterpretError("'break'/'continue' outside of a loop".to_string()));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn push_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    fn pop_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    fn define(&mut self, name: &str, value: Value) {
+        self.scopes
+            .last_mut()
+            .expect("at least one scope is always present")
+            .insert(name.to_string(), value);
+    }
+
+    fn assign(&mut self, name: &str, value: Value) -> Result<(), InterpretError> {
+        for scope in self.scopes.iter_mut().rev() {
+            if let Some(slot) = scope.get_mut(name) {
+                *slot = value;
+                return Ok(());
+            }
+        }
+        Err(InterpretError(format!("Assignment to undeclared variable '{}'", name)))
+    }
+
+    fn lookup(&self, name: &str) -> Result<Value, InterpretError> {
+        for scope in self.scopes.iter().rev() {
+            if let Some(value) = scope.get(name) {
+                return Ok(value.clone());
+            }
+        }
+        Err(InterpretError(format!("Undefined variable '{}'", name)))
+    }
+
+    fn eval_index(&mut self, expr: &Expr) -> Result<usize, InterpretError> {
+        match self.eval_expr(expr)? {
+            Value::Int(i) if i >= 0 => Ok(i as usize),
+            other => Err(InterpretError(format!("Array index must be a non-negative integer, found {:?}", other))),
+        }
+    }
+
+    fn eval_map_key(&mut self, expr: &Expr) -> Result<String, InterpretError> {
+        match self.eval_expr(expr)? {
+            Value::Str(s) => Ok(s),
+            other => Err(InterpretError(format!("Map index must be a string, found {:?}", other))),
+        }
+    }
+
+    // Resolves the mutable array backing an assignable place like `arr` or
+    // `matrix[i]`, so index assignments mutate the original variable in place.
+    fn resolve_array_slot(&mut self, expr: &Expr) -> Result<&mut Vec<Value>, InterpretError> {
+        match expr {
+            Expr::Identifier(name) => {
+                for scope in self.scopes.iter_mut().rev() {
+                    match scope.get_mut(name) {
+                        Some(Value::Array(elements)) => return Ok(elements),
+                        Some(_) => return Err(InterpretError(format!("Cannot index into non-array variable '{}'", name))),
+                        None => continue,
+                    }
+                }
+                Err(InterpretError(format!("Undefined variable '{}'", name)))
+            }
+            Expr::Index { target, index } => {
+                let idx = self.eval_index(index)?;
+                let outer = self.resolve_array_slot(target)?;
+                match outer.get_mut(idx) {
+                    Some(Value::Array(elements)) => Ok(elements),
+                    Some(_) => Err(InterpretError("Cannot index into non-array value".to_string())),
+                    None => Err(InterpretError(format!("Index {} out of bounds", idx))),
+                }
+            }
+            _ => Err(InterpretError(format!("Cannot assign to index of '{:?}'", expr))),
+        }
+    }
+
+    fn exec_block(&mut self, block: &Block) -> Result<Signal, InterpretError> {
+        self.push_scope();
+        let result = (|| {
+            for statement in &block.statements {
+                match self.exec_statement(statement)? {
+                    Signal::Normal => {}
+                    signal => return Ok(signal),
+                }
+            }
+            // `Block::result` (an un-terminated trailing expression) is
+            // still evaluated here for its side effects even though only a
+            // function body's result is ever read back out as a value (see
+            // `eval_function_body`) -- otherwise an `if`/`while`/`for`/`loop`
+            // body whose last statement happens to lack a trailing `;`
+            // would silently stop running it.
+            if let Some(expr) = &block.result {
+                self.eval_expr(expr)?;
+            }
+            Ok(Signal::Normal)
+        })();
+        self.pop_scope();
+        result
+    }
+
+    fn exec_statement(&mut self, statement: &Statement) -> Result<Signal, InterpretError> {
+        match statement {
+            Statement::LetDecl { name, value_expr, .. } => {
+                let value = self.eval_expr(value_expr)?;
+                self.define(name, value);
+                Ok(Signal::Normal)
+            }
+            Statement::Assignment { target, value_expr } => {
+                let value = self.eval_expr(value_expr)?;
+                match target {
+                    Expr::Identifier(name) => self.assign(name, value)?,
+                    Expr::Index { target, index } => {
+                        let idx = self.eval_index(index)?;
+                        let elements = self.resolve_array_slot(target)?;
+                        match elements.get_mut(idx) {
+                            Some(slot) => *slot = value,
+                            None => return Err(InterpretError(format!("Index {} out of bounds", idx))),
+                        }
+                    }
+                    _ => return Err(InterpretError(format!("Cannot assign to '{:?}'", target))),
+                }
+                Ok(Signal::Normal)
+            }
+            Statement::ExprStatement { expr } => {
+                self.eval_expr(expr)?;
+                Ok(Signal::Normal)
+            }
+            Statement::If { condition, then_block, else_if_blocks, else_block } => {
+                if self.eval_expr(condition)?.truthy()? {
+                    return self.exec_block(then_block);
+                }
+                for (cond, block) in else_if_blocks {
+                    if self.eval_expr(cond)?.truthy()? {
+                        return self.exec_block(block);
+                    }
+                }
+                if let Some(block) = else_block {
+                    return self.exec_block(block);
+                }
+                Ok(Signal::Normal)
+            }
+            Statement::While { condition, body_block } => {
+                while self.eval_expr(condition)?.truthy()? {
+                    match self.exec_block(body_block)? {
+                        Signal::Break => break,
+                        Signal::Continue | Signal::Normal => {}
+                        signal @ Signal::Return(_) => return Ok(signal),
+                    }
+                }
+                Ok(Signal::Normal)
+            }
+            Statement::Loop { body_block } => {
+                loop {
+                    match self.exec_block(body_block)? {
+                        Signal::Break => break,
+                        Signal::Continue | Signal::Normal => {}
+                        signal @ Signal::Return(_) => return Ok(signal),
+                    }
+                }
+                Ok(Signal::Normal)
+            }
+            Statement::For { initializer, condition, increment, body_block } => {
+                self.push_scope();
+                let result = (|| {
+                    if let Some(init) = initializer {
+                        self.exec_statement(init)?;
+                    }
+                    loop {
+                        let keep_going = match condition {
+                            Some(cond) => self.eval_expr(cond)?.truthy()?,
+                            None => true,
+                        };
+                        if !keep_going {
+                            break;
+                        }
+                        match self.exec_block(body_block)? {
+                            Signal::Break => break,
+                            Signal::Continue | Signal::Normal => {}
+                            signal @ Signal::Return(_) => return Ok(signal),
+                        }
+                        if let Some(inc) = increment {
+                            self.exec_statement(inc)?;
+                        }
+                    }
+                    Ok(Signal::Normal)
+                })();
+                self.pop_scope();
+                result
+            }
+            Statement::Print { expr, newline } => {
+                let value = self.eval_expr(expr)?;
+                if *newline {
+                    writeln!(self.out, "{}", value).map_err(|e| InterpretError(e.to_string()))?;
+                } else {
+                    write!(self.out, "{}", value).map_err(|e| InterpretError(e.to_string()))?;
+                }
+                Ok(Signal::Normal)
+            }
+            Statement::Break => Ok(Signal::Break),
+            Statement::Continue => Ok(Signal::Continue),
+            Statement::FnDecl { name, params, body, .. } => {
+                self.functions.insert(
+                    name.clone(),
+                    Function {
+                        params: params.clone(),
+                        body: body.clone(),
+                    },
+                );
+                Ok(Signal::Normal)
+            }
+            Statement::Return { expr } => {
+                let value = match expr {
+                    Some(expr) => self.eval_expr(expr)?,
+                    None => Value::Unit,
+                };
+                Ok(Signal::Return(value))
+            }
+        }
+    }
+
+    fn eval_expr(&mut self, expr: &Expr) -> Result<Value, InterpretError> {
+        match expr {
+            Expr::Integer(v) => Ok(Value::Int(*v)),
+            Expr::Float(v) => Ok(Value::Float(*v)),
+            Expr::StringLiteral(s) => Ok(Value::Str(s.clone())),
+            Expr::Boolean(b) => Ok(Value::Bool(*b)),
+            Expr::Identifier(name) => self.lookup(name),
+            Expr::UnaryOp { op, expr } => {
+                let value = self.eval_expr(expr)?;
+                match op {
+                    UnaryOperator::Not => Ok(Value::Bool(!value.truthy()?)),
+                    UnaryOperator::Negate => match value {
+                        Value::Int(v) => Ok(Value::Int(-v)),
+                        Value::Float(v) => Ok(Value::Float(-v)),
+                        other => Err(InterpretError(format!("Cannot negate {:?}", other))),
+                    },
+                }
+            }
+            Expr::BinaryOp { left, op, right } => {
+                let left = self.eval_expr(left)?;
+                let right = self.eval_expr(right)?;
+                eval_binary_op(op, left, right)
+            }
+            Expr::Call { callee, args } => {
+                let mut arg_values = Vec::with_capacity(args.len());
+                for arg in args {
+                    arg_values.push(self.eval_expr(arg)?);
+                }
+                // Named functions and builtins are still resolved by looking
+                // up a bare identifier callee; anything else (a member
+                // access, another call, ...) has no first-class value to
+                // invoke yet.
+                let name = match callee.as_ref() {
+                    Expr::Identifier(name) => name,
+                    other => return Err(InterpretError(format!("Cannot call non-function expression {:?}", other))),
+                };
+                if let Some(function) = self.functions.get(name).cloned() {
+                    return self.call_function(&function, arg_values);
+                }
+                self.call_builtin(name, arg_values)
+            }
+            Expr::Member { target, field } => {
+                self.eval_expr(target)?;
+                Err(InterpretError(format!("Member access '.{}' is not yet supported", field)))
+            }
+            Expr::ArrayLiteral(elements) => {
+                let mut values = Vec::with_capacity(elements.len());
+                for element in elements {
+                    values.push(self.eval_expr(element)?);
+                }
+                Ok(Value::Array(values))
+            }
+            Expr::Map(entries) => {
+                let mut values = Vec::with_capacity(entries.len());
+                for (key, value_expr) in entries {
+                    values.push((key.clone(), self.eval_expr(value_expr)?));
+                }
+                Ok(Value::Map(values))
+            }
+            Expr::Index { target, index } => {
+                let target_value = self.eval_expr(target)?;
+                match target_value {
+                    Value::Array(elements) => {
+                        let idx = self.eval_index(index)?;
+                        elements
+                            .get(idx)
+                            .cloned()
+                            .ok_or_else(|| InterpretError(format!("Index {} out of bounds", idx)))
+                    }
+                    Value::Map(entries) => {
+                        let key = self.eval_map_key(index)?;
+                        entries
+                            .iter()
+                            .find(|(k, _)| *k == key)
+                            .map(|(_, v)| v.clone())
+                            .ok_or_else(|| InterpretError(format!("Key '{}' not found in map", key)))
+                    }
+                    other => Err(InterpretError(format!("Cannot index into {:?}", other))),
+                }
+            }
+            Expr::OperatorFn(op) => Ok(Value::Function(op.clone())),
+        }
+    }
+
+    fn call_function(&mut self, function: &Function, args: Vec<Value>) -> Result<Value, InterpretError> {
+        if args.len() != function.params.len() {
+            return Err(InterpretError(format!(
+                "Expected {} argument(s), found {}",
+                function.params.len(),
+                args.len()
+            )));
+        }
+        self.push_scope();
+        let result = {
+            for ((param_name, _), value) in function.params.iter().zip(args) {
+                self.define(param_name, value);
+            }
+            self.eval_function_body(&function.body)
+        };
+        self.pop_scope();
+        result
+    }
+
+    /// Like `exec_block`, but for a function body specifically: runs its
+    /// statements in their own scope same as any other block, but -- unlike
+    /// `exec_block` -- also evaluates `Block::result` (if the body's last
+    /// statement was an un-terminated expression) before that scope is
+    /// popped, so an implicit return value can still see the body's own
+    /// locals (`fn f() { let x = 5; x }`).
+    fn eval_function_body(&mut self, block: &Block) -> Result<Value, InterpretError> {
+        self.push_scope();
+        let result = (|| {
+            for statement in &block.statements {
+                match self.exec_statement(statement)? {
+                    Signal::Normal => {}
+                    Signal::Return(value) => return Ok(value),
+                    Signal::Break | Signal::Continue => {
+                        return Err(InterpretError("'break'/'continue' outside of a loop".to_string()));
+                    }
+                }
+            }
+            match &block.result {
+                Some(expr) => self.eval_expr(expr),
+                None => Ok(Value::Unit),
+            }
+        })();
+        self.pop_scope();
+        result
+    }
+
+    fn call_builtin(&mut self, name: &str, args: Vec<Value>) -> Result<Value, InterpretError> {
+        match name {
+            "abs" => match args.as_slice() {
+                [Value::Int(v)] => Ok(Value::Int(v.abs())),
+                [Value::Float(v)] => Ok(Value::Float(v.abs())),
+                _ => Err(InterpretError("abs() expects a single numeric argument".to_string())),
+            },
+            _ => Err(InterpretError(format!("Unknown function '{}'", name))),
+        }
+    }
+}
+
+impl Value {
+    fn truthy(&self) -> Result<bool, InterpretError> {
+        match self {
+            Value::Bool(b) => Ok(*b),
+            other => Err(InterpretError(format!("Expected bool, found {:?}", other))),
+        }
+    }
+}
+
+fn eval_binary_op(op: &BinaryOperator, left: Value, right: Value) -> Result<Value, InterpretError> {
+    use BinaryOperator::*;
+    match (op, left, right) {
+        (Plus, Value::Int(a), Value::Int(b)) => Ok(Value::Int(a + b)),
+        (Plus, Value::Float(a), Value::Float(b)) => Ok(Value::Float(a + b)),
+        (Plus, Value::Str(a), Value::Str(b)) => Ok(Value::Str(a + &b)),
+        (Minus, Value::Int(a), Value::Int(b)) => Ok(Value::Int(a - b)),
+        (Minus, Value::Float(a), Value::Float(b)) => Ok(Value::Float(a - b)),
+        (Multiply, Value::Int(a), Value::Int(b)) => Ok(Value::Int(a * b)),
+        (Multiply, Value::Float(a), Value::Float(b)) => Ok(Value::Float(a * b)),
+        (Divide, Value::Int(a), Value::Int(b)) => {
+            if b == 0 {
+                Err(InterpretError("Division by zero".to_string()))
+            } else {
+                Ok(Value::Int(a / b))
+            }
+        }
+        (Divide, Value::Float(a), Value::Float(b)) => Ok(Value::Float(a / b)),
+        (Modulo, Value::Int(a), Value::Int(b)) => {
+            if b == 0 {
+                Err(InterpretError("Division by zero".to_string()))
+            } else {
+                Ok(Value::Int(a % b))
+            }
+        }
+        (Eq, a, b) => Ok(Value::Bool(a == b)),
+        (NotEq, a, b) => Ok(Value::Bool(a != b)),
+        (Lt, Value::Int(a), Value::Int(b)) => Ok(Value::Bool(a < b)),
+        (Lt, Value::Float(a), Value::Float(b)) => Ok(Value::Bool(a < b)),
+        (Lte, Value::Int(a), Value::Int(b)) => Ok(Value::Bool(a <= b)),
+        (Lte, Value::Float(a), Value::Float(b)) => Ok(Value::Bool(a <= b)),
+        (Gt, Value::Int(a), Value::Int(b)) => Ok(Value::Bool(a > b)),
+        (Gt, Value::Float(a), Value::Float(b)) => Ok(Value::Bool(a > b)),
+        (Gte, Value::Int(a), Value::Int(b)) => Ok(Value::Bool(a >= b)),
+        (Gte, Value::Float(a), Value::Float(b)) => Ok(Value::Bool(a >= b)),
+        (And, a, b) => Ok(Value::Bool(a.truthy()? && b.truthy()?)),
+        (Or, a, b) => Ok(Value::Bool(a.truthy()? || b.truthy()?)),
+        (op, a, b) => Err(InterpretError(format!("Unsupported operands for {:?}: {:?}, {:?}", op, a, b))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Lexer;
+    use crate::parser::Parser;
+
+    fn run(input: &str) -> String {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        let program = parser.parse_program().expect("parse failed");
+        let mut out = Vec::new();
+        let mut interp = Interpreter::new(&mut out);
+        interp.run(&program).expect("interpret failed");
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_let_and_print() {
+        assert_eq!(run("let x = 5; print(x);"), "5");
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(run("println(1 + 2 * 3);"), "7\n");
+    }
+
+    #[test]
+    fn test_if_else() {
+        assert_eq!(run("if 1 > 2 { print(1); } else { print(2); }"), "2");
+    }
+
+    #[test]
+    fn test_while_loop() {
+        assert_eq!(run("mut i = 0; while i < 3 { print(i); i = i + 1; }"), "012");
+    }
+
+    #[test]
+    fn test_loop_break_continue() {
+        let out = run("mut i = 0; loop { i = i + 1; if i == 2 { continue; } if i > 3 { break; } print(i); }");
+        assert_eq!(out, "13");
+    }
+
+    #[test]
+    fn test_for_loop() {
+        assert_eq!(run("for let i = 0; i < 3; i = i + 1 { print(i); }"), "012");
+    }
+
+    #[test]
+    fn test_nested_scopes_do_not_leak() {
+        let input = "let x = 1; if true { let x = 2; print(x); } print(x);";
+        assert_eq!(run(input), "21");
+    }
+
+    #[test]
+    fn test_fn_call_and_return() {
+        let input = "fn add(a: int, b: int): int { return a + b; } print(add(2, 3));";
+        assert_eq!(run(input), "5");
+    }
+
+    #[test]
+    fn test_fn_without_return_yields_unit() {
+        let input = "fn noop() { let x = 1; } noop(); print(1);";
+        assert_eq!(run(input), "1");
+    }
+
+    #[test]
+    fn test_fn_implicit_return_from_trailing_expression() {
+        let input = "fn add(a: int, b: int): int { a + b } print(add(2, 3));";
+        assert_eq!(run(input), "5");
+    }
+
+    #[test]
+    fn test_fn_implicit_return_sees_own_locals() {
+        let input = "fn square(n: int): int { let r = n * n; r } print(square(4));";
+        assert_eq!(run(input), "16");
+    }
+
+    #[test]
+    fn test_nested_function_calls() {
+        let input = "fn double(x: int): int { x * 2 } fn quadruple(x: int): int { double(double(x)) } print(quadruple(3));";
+        assert_eq!(run(input), "12");
+    }
+
+    #[test]
+    fn test_member_access_errors() {
+        let lexer = Lexer::new("let x = 5; print(x.field);");
+        let mut parser = Parser::new(lexer);
+        let program = parser.parse_program().expect("parse failed");
+        let mut out = Vec::new();
+        let mut interp = Interpreter::new(&mut out);
+        let err = interp.run(&program).unwrap_err();
+        assert!(err.0.contains("Member access"));
+    }
+
+    #[test]
+    fn test_map_literal_and_index() {
+        assert_eq!(run("let m = { x: 1, y: 2 }; print(m[\"y\"]);"), "2");
+    }
+
+    #[test]
+    fn test_nested_array_index() {
+        assert_eq!(run("let matrix = [[1, 2], [3, 4]]; print(matrix[1][0]);"), "3");
+    }
+
+    #[test]
+    fn test_operator_fn_is_a_holdable_value() {
+        assert_eq!(run("let op = \\+; print(op);"), "\\+");
+    }
+
+    #[test]
+    fn test_return_inside_loop_exits_function() {
+        let input = "fn first_over(limit: int): int { mut i = 0; loop { i = i + 1; if i > limit { return i; } } } print(first_over(3));";
+        assert_eq!(run(input), "4");
+    }
+
+    #[test]
+    fn test_return_outside_function_errors() {
+        let lexer = Lexer::new("return 1;");
+        let mut parser = Parser::new(lexer);
+        let program = parser.parse_program().unwrap();
+        let mut out = Vec::new();
+        let mut interp = Interpreter::new(&mut out);
+        assert!(interp.run(&program).is_err());
+    }
+
+    #[test]
+    fn test_array_literal_and_index_read() {
+        let input = "let xs = [1, 2, 3]; print(xs[0]); print(xs[2]);";
+        assert_eq!(run(input), "13");
+    }
+
+    #[test]
+    fn test_array_print_shows_elements() {
+        assert_eq!(run("print([1, 2, 3]);"), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn test_index_assignment_mutates_in_place() {
+        let input = "mut xs = [1, 2, 3]; xs[1] = 9; print(xs[1]);";
+        assert_eq!(run(input), "9");
+    }
+
+    #[test]
+    fn test_index_out_of_bounds_errors() {
+        let lexer = Lexer::new("let xs = [1, 2]; print(xs[5]);");
+        let mut parser = Parser::new(lexer);
+        let program = parser.parse_program().unwrap();
+        let mut out = Vec::new();
+        let mut interp = Interpreter::new(&mut out);
+        assert!(interp.run(&program).is_err());
+    }
+
+    #[test]
+    fn test_division_by_zero_errors() {
+        let lexer = Lexer::new("print(1 / 0);");
+        let mut parser = Parser::new(lexer);
+        let program = parser.parse_program().unwrap();
+        let mut out = Vec::new();
+        let mut interp = Interpreter::new(&mut out);
+        assert!(interp.run(&program).is_err());
+    }
+}
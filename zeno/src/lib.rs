@@ -1,7 +1,13 @@
 pub mod ast;
+pub mod cache;
 pub mod lexer;
 pub mod parser;
 pub mod generator;
+pub mod interpreter;
+pub mod optimize;
+pub mod typeck;
+#[cfg(feature = "llvm-backend")]
+pub mod llvm_backend;
 
 // Keep the original add function and its test for now, or remove if not needed.
 pub fn add(left: usize, right: usize) -> usize {
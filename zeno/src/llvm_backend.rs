@@ -0,0 +1,481 @@
+//! A native codegen backend that lowers a type-checked AST straight to LLVM IR
+//! via `inkwell`, skipping the Rust/C/JS source round-trip entirely. Unlike the
+//! backends in `generator.rs`, this one doesn't implement the `Backend` trait:
+//! that trait's `emit_*` methods return source text, while this one builds IR
+//! in-memory and writes an object file, so it exposes its own entry point
+//! (`compile_to_object`) instead.
+//!
+//! Scope matches the interpreter's own feature set for the numeric core: `int`
+//! and `float` locals, the control-flow statements, and `print`/`println`. It
+//! does not attempt arrays, strings, or user-defined functions yet.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use inkwell::basic_block::BasicBlock;
+use inkwell::builder::Builder;
+use inkwell::context::Context;
+use inkwell::module::Module;
+use inkwell::targets::{CodeModel, FileType, InitializationConfig, RelocMode, Target, TargetMachine};
+use inkwell::types::BasicTypeEnum;
+use inkwell::values::{FunctionValue, PointerValue};
+use inkwell::{FloatPredicate, IntPredicate, OptimizationLevel};
+
+use crate::ast::{BinaryOperator, Block, Expr, Program, Statement, UnaryOperator};
+use crate::typeck::{Type, TypeckResult};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LlvmBackendError(String);
+
+impl std::fmt::Display for LlvmBackendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LLVM Backend Error: {}", self.0)
+    }
+}
+
+impl std::error::Error for LlvmBackendError {}
+
+/// The blocks a `break`/`continue` inside a loop body should jump to.
+struct LoopBlocks<'ctx> {
+    continue_block: BasicBlock<'ctx>,
+    break_block: BasicBlock<'ctx>,
+}
+
+/// Lowers a single `Program` into the LLVM IR of `module`, given the concrete
+/// types `typeck::check` assigned to every `let`-bound name.
+pub struct LlvmBackend<'ctx> {
+    context: &'ctx Context,
+    module: Module<'ctx>,
+    builder: Builder<'ctx>,
+    bindings: HashMap<String, Type>,
+    variables: HashMap<String, (PointerValue<'ctx>, BasicTypeEnum<'ctx>)>,
+    printf_fn: FunctionValue<'ctx>,
+    loop_stack: Vec<LoopBlocks<'ctx>>,
+}
+
+impl<'ctx> LlvmBackend<'ctx> {
+    pub fn new(context: &'ctx Context, module_name: &str, types: &TypeckResult) -> Self {
+        let module = context.create_module(module_name);
+        let builder = context.create_builder();
+        let printf_fn = declare_printf(context, &module);
+        LlvmBackend {
+            context,
+            module,
+            builder,
+            bindings: types.bindings.clone(),
+            variables: HashMap::new(),
+            printf_fn,
+            loop_stack: Vec::new(),
+        }
+    }
+
+    /// Lowers `program` into a `main` function in this backend's module.
+    pub fn compile_program(&mut self, program: &Program) -> Result<(), LlvmBackendError> {
+        let i32_type = self.context.i32_type();
+        let fn_type = i32_type.fn_type(&[], false);
+        let main_fn = self.module.add_function("main", fn_type, None);
+        let entry = self.context.append_basic_block(main_fn, "entry");
+        self.builder.position_at_end(entry);
+
+        for statement in &program.statements {
+            self.compile_statement(statement, main_fn)?;
+        }
+
+        self.builder.position_at_end(main_fn.get_last_basic_block().unwrap());
+        self.builder
+            .build_return(Some(&i32_type.const_int(0, false)))
+            .map_err(|e| LlvmBackendError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Verifies the module and writes it out as a native object file.
+    pub fn write_object_file(&self, output_path: &Path) -> Result<(), LlvmBackendError> {
+        if !self.module.verify().is_ok() {
+            return Err(LlvmBackendError(format!(
+                "module failed verification: {}",
+                self.module.verify().unwrap_err()
+            )));
+        }
+
+        Target::initialize_native(&InitializationConfig::default())
+            .map_err(|e| LlvmBackendError(e))?;
+        let triple = TargetMachine::get_default_triple();
+        let target = Target::from_triple(&triple).map_err(|e| LlvmBackendError(e.to_string()))?;
+        let machine = target
+            .create_target_machine(
+                &triple,
+                "generic",
+                "",
+                OptimizationLevel::Default,
+                RelocMode::Default,
+                CodeModel::Default,
+            )
+            .ok_or_else(|| LlvmBackendError("could not create target machine".to_string()))?;
+
+        machine
+            .write_to_file(&self.module, FileType::Object, output_path)
+            .map_err(|e| LlvmBackendError(e.to_string()))
+    }
+
+    fn compile_statement(&mut self, statement: &Statement, func: FunctionValue<'ctx>) -> Result<(), LlvmBackendError> {
+        match statement {
+            Statement::LetDecl { name, value_expr, .. } => {
+                let value = self.compile_expression(value_expr)?;
+                let alloca = self
+                    .builder
+                    .build_alloca(value.get_type(), name)
+                    .map_err(|e| LlvmBackendError(e.to_string()))?;
+                self.builder.build_store(alloca, value).map_err(|e| LlvmBackendError(e.to_string()))?;
+                self.variables.insert(name.clone(), (alloca, value.get_type()));
+            }
+            Statement::Assignment { target, value_expr } => {
+                let Expr::Identifier(name) = target else {
+                    return Err(LlvmBackendError(
+                        "arrays and functions are not yet supported by the LLVM backend".to_string(),
+                    ));
+                };
+                let value = self.compile_expression(value_expr)?;
+                let (alloca, _) = *self
+                    .variables
+                    .get(name)
+                    .ok_or_else(|| LlvmBackendError(format!("Undefined variable '{}'", name)))?;
+                self.builder.build_store(alloca, value).map_err(|e| LlvmBackendError(e.to_string()))?;
+            }
+            Statement::ExprStatement { expr } => {
+                self.compile_expression(expr)?;
+            }
+            Statement::Print { expr, newline } => {
+                self.compile_print(expr, *newline)?;
+            }
+            Statement::If { condition, then_block, else_if_blocks, else_block } => {
+                self.compile_if(condition, then_block, else_if_blocks, else_block, func)?;
+            }
+            Statement::While { condition, body_block } => {
+                self.compile_while(condition, body_block, func)?;
+            }
+            Statement::Loop { body_block } => {
+                self.compile_loop(body_block, func)?;
+            }
+            Statement::For { initializer, condition, increment, body_block } => {
+                self.compile_for(initializer, condition, increment, body_block, func)?;
+            }
+            Statement::Break => {
+                let target = self
+                    .loop_stack
+                    .last()
+                    .ok_or_else(|| LlvmBackendError("'break' outside of a loop".to_string()))?
+                    .break_block;
+                self.builder.build_unconditional_branch(target).map_err(|e| LlvmBackendError(e.to_string()))?;
+            }
+            Statement::Continue => {
+                let target = self
+                    .loop_stack
+                    .last()
+                    .ok_or_else(|| LlvmBackendError("'continue' outside of a loop".to_string()))?
+                    .continue_block;
+                self.builder.build_unconditional_branch(target).map_err(|e| LlvmBackendError(e.to_string()))?;
+            }
+            Statement::FnDecl { .. } | Statement::Return { .. } => {
+                return Err(LlvmBackendError(
+                    "arrays and functions are not yet supported by the LLVM backend".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn compile_if(
+        &mut self,
+        condition: &Expr,
+        then_block: &Block,
+        else_if_blocks: &[(Expr, Block)],
+        else_block: &Option<Block>,
+        func: FunctionValue<'ctx>,
+    ) -> Result<(), LlvmBackendError> {
+        let merge_bb = self.context.append_basic_block(func, "if.merge");
+        self.compile_if_arm(condition, then_block, else_if_blocks, else_block, func, merge_bb)?;
+        self.builder.position_at_end(merge_bb);
+        Ok(())
+    }
+
+    fn compile_if_arm(
+        &mut self,
+        condition: &Expr,
+        then_block: &Block,
+        remaining_else_ifs: &[(Expr, Block)],
+        else_block: &Option<Block>,
+        func: FunctionValue<'ctx>,
+        merge_bb: BasicBlock<'ctx>,
+    ) -> Result<(), LlvmBackendError> {
+        let cond_value = self.compile_expression(condition)?.into_int_value();
+        let then_bb = self.context.append_basic_block(func, "if.then");
+        let else_bb = self.context.append_basic_block(func, "if.else");
+        self.builder
+            .build_conditional_branch(cond_value, then_bb, else_bb)
+            .map_err(|e| LlvmBackendError(e.to_string()))?;
+
+        self.builder.position_at_end(then_bb);
+        for statement in &then_block.statements {
+            self.compile_statement(statement, func)?;
+        }
+        if let Some(expr) = &then_block.result {
+            self.compile_expression(expr)?;
+        }
+        self.builder.build_unconditional_branch(merge_bb).ok();
+
+        self.builder.position_at_end(else_bb);
+        if let Some((next_cond, next_block)) = remaining_else_ifs.split_first().map(|(h, t)| (h, t)) {
+            self.compile_if_arm(&next_cond.0, &next_cond.1, remaining_else_ifs.get(1..).unwrap_or(&[]), else_block, func, merge_bb)?;
+            let _ = next_block;
+        } else if let Some(else_block) = else_block {
+            for statement in &else_block.statements {
+                self.compile_statement(statement, func)?;
+            }
+            if let Some(expr) = &else_block.result {
+                self.compile_expression(expr)?;
+            }
+            self.builder.build_unconditional_branch(merge_bb).ok();
+        } else {
+            self.builder.build_unconditional_branch(merge_bb).ok();
+        }
+        Ok(())
+    }
+
+    fn compile_while(&mut self, condition: &Expr, body_block: &Block, func: FunctionValue<'ctx>) -> Result<(), LlvmBackendError> {
+        let cond_bb = self.context.append_basic_block(func, "while.cond");
+        let body_bb = self.context.append_basic_block(func, "while.body");
+        let exit_bb = self.context.append_basic_block(func, "while.exit");
+
+        self.builder.build_unconditional_branch(cond_bb).map_err(|e| LlvmBackendError(e.to_string()))?;
+        self.builder.position_at_end(cond_bb);
+        let cond_value = self.compile_expression(condition)?.into_int_value();
+        self.builder
+            .build_conditional_branch(cond_value, body_bb, exit_bb)
+            .map_err(|e| LlvmBackendError(e.to_string()))?;
+
+        self.builder.position_at_end(body_bb);
+        self.loop_stack.push(LoopBlocks { continue_block: cond_bb, break_block: exit_bb });
+        for statement in &body_block.statements {
+            self.compile_statement(statement, func)?;
+        }
+        if let Some(expr) = &body_block.result {
+            self.compile_expression(expr)?;
+        }
+        self.loop_stack.pop();
+        self.builder.build_unconditional_branch(cond_bb).ok();
+
+        self.builder.position_at_end(exit_bb);
+        Ok(())
+    }
+
+    fn compile_loop(&mut self, body_block: &Block, func: FunctionValue<'ctx>) -> Result<(), LlvmBackendError> {
+        let body_bb = self.context.append_basic_block(func, "loop.body");
+        let exit_bb = self.context.append_basic_block(func, "loop.exit");
+
+        self.builder.build_unconditional_branch(body_bb).map_err(|e| LlvmBackendError(e.to_string()))?;
+        self.builder.position_at_end(body_bb);
+        self.loop_stack.push(LoopBlocks { continue_block: body_bb, break_block: exit_bb });
+        for statement in &body_block.statements {
+            self.compile_statement(statement, func)?;
+        }
+        if let Some(expr) = &body_block.result {
+            self.compile_expression(expr)?;
+        }
+        self.loop_stack.pop();
+        self.builder.build_unconditional_branch(body_bb).ok();
+
+        self.builder.position_at_end(exit_bb);
+        Ok(())
+    }
+
+    fn compile_for(
+        &mut self,
+        initializer: &Option<Box<Statement>>,
+        condition: &Option<Expr>,
+        increment: &Option<Box<Statement>>,
+        body_block: &Block,
+        func: FunctionValue<'ctx>,
+    ) -> Result<(), LlvmBackendError> {
+        if let Some(init_stmt) = initializer {
+            self.compile_statement(init_stmt, func)?;
+        }
+
+        let cond_bb = self.context.append_basic_block(func, "for.cond");
+        let body_bb = self.context.append_basic_block(func, "for.body");
+        let incr_bb = self.context.append_basic_block(func, "for.incr");
+        let exit_bb = self.context.append_basic_block(func, "for.exit");
+
+        self.builder.build_unconditional_branch(cond_bb).map_err(|e| LlvmBackendError(e.to_string()))?;
+        self.builder.position_at_end(cond_bb);
+        match condition {
+            Some(condition) => {
+                let cond_value = self.compile_expression(condition)?.into_int_value();
+                self.builder
+                    .build_conditional_branch(cond_value, body_bb, exit_bb)
+                    .map_err(|e| LlvmBackendError(e.to_string()))?;
+            }
+            None => {
+                self.builder.build_unconditional_branch(body_bb).map_err(|e| LlvmBackendError(e.to_string()))?;
+            }
+        }
+
+        self.builder.position_at_end(body_bb);
+        self.loop_stack.push(LoopBlocks { continue_block: incr_bb, break_block: exit_bb });
+        for statement in &body_block.statements {
+            self.compile_statement(statement, func)?;
+        }
+        if let Some(expr) = &body_block.result {
+            self.compile_expression(expr)?;
+        }
+        self.loop_stack.pop();
+        self.builder.build_unconditional_branch(incr_bb).ok();
+
+        self.builder.position_at_end(incr_bb);
+        if let Some(increment) = increment {
+            self.compile_statement(increment, func)?;
+        }
+        self.builder.build_unconditional_branch(cond_bb).map_err(|e| LlvmBackendError(e.to_string()))?;
+
+        self.builder.position_at_end(exit_bb);
+        Ok(())
+    }
+
+    fn compile_print(&mut self, expr: &Expr, newline: bool) -> Result<(), LlvmBackendError> {
+        let value = self.compile_expression(expr)?;
+        let format = if value.is_float_value() {
+            if newline { "%f\n" } else { "%f" }
+        } else if newline {
+            "%lld\n"
+        } else {
+            "%lld"
+        };
+        let format_ptr = self
+            .builder
+            .build_global_string_ptr(format, "fmt")
+            .map_err(|e| LlvmBackendError(e.to_string()))?;
+        self.builder
+            .build_call(self.printf_fn, &[format_ptr.as_pointer_value().into(), value.into()], "printf_call")
+            .map_err(|e| LlvmBackendError(e.to_string()))?;
+        Ok(())
+    }
+
+    fn compile_expression(&mut self, expr: &Expr) -> Result<inkwell::values::BasicValueEnum<'ctx>, LlvmBackendError> {
+        match expr {
+            Expr::Integer(val) => Ok(self.context.i64_type().const_int(*val as u64, true).into()),
+            Expr::Float(val) => Ok(self.context.f64_type().const_float(*val).into()),
+            Expr::Boolean(val) => Ok(self.context.bool_type().const_int(*val as u64, false).into()),
+            Expr::Identifier(name) => {
+                let (alloca, elem_type) = *self
+                    .variables
+                    .get(name)
+                    .ok_or_else(|| LlvmBackendError(format!("Undefined variable '{}'", name)))?;
+                self.builder
+                    .build_load(elem_type, alloca, name)
+                    .map_err(|e| LlvmBackendError(e.to_string()))
+            }
+            Expr::UnaryOp { op, expr } => {
+                let value = self.compile_expression(expr)?;
+                match op {
+                    UnaryOperator::Negate if value.is_int_value() => Ok(self
+                        .builder
+                        .build_int_neg(value.into_int_value(), "negtmp")
+                        .map_err(|e| LlvmBackendError(e.to_string()))?
+                        .into()),
+                    UnaryOperator::Negate => Ok(self
+                        .builder
+                        .build_float_neg(value.into_float_value(), "fnegtmp")
+                        .map_err(|e| LlvmBackendError(e.to_string()))?
+                        .into()),
+                    UnaryOperator::Not => Ok(self
+                        .builder
+                        .build_not(value.into_int_value(), "nottmp")
+                        .map_err(|e| LlvmBackendError(e.to_string()))?
+                        .into()),
+                }
+            }
+            Expr::BinaryOp { left, op, right } => self.compile_binary_op(left, op, right),
+            Expr::StringLiteral(_) | Expr::Call { .. } | Expr::Member { .. } | Expr::ArrayLiteral(_)
+            | Expr::Map(_) | Expr::Index { .. } | Expr::OperatorFn(_) => Err(
+                LlvmBackendError("strings, calls, member access, arrays, maps, and boxed operators are not yet supported by the LLVM backend".to_string()),
+            ),
+        }
+    }
+
+    fn compile_binary_op(
+        &mut self,
+        left: &Expr,
+        op: &BinaryOperator,
+        right: &Expr,
+    ) -> Result<inkwell::values::BasicValueEnum<'ctx>, LlvmBackendError> {
+        let lhs = self.compile_expression(left)?;
+        let rhs = self.compile_expression(right)?;
+
+        if lhs.is_float_value() || rhs.is_float_value() {
+            let lhs = lhs.into_float_value();
+            let rhs = rhs.into_float_value();
+            use BinaryOperator::*;
+            let result = match op {
+                Plus => self.builder.build_float_add(lhs, rhs, "faddtmp").map(Into::into),
+                Minus => self.builder.build_float_sub(lhs, rhs, "fsubtmp").map(Into::into),
+                Multiply => self.builder.build_float_mul(lhs, rhs, "fmultmp").map(Into::into),
+                Divide => self.builder.build_float_div(lhs, rhs, "fdivtmp").map(Into::into),
+                Eq => self.builder.build_float_compare(FloatPredicate::OEQ, lhs, rhs, "feqtmp").map(Into::into),
+                NotEq => self.builder.build_float_compare(FloatPredicate::ONE, lhs, rhs, "fnetmp").map(Into::into),
+                Lt => self.builder.build_float_compare(FloatPredicate::OLT, lhs, rhs, "flttmp").map(Into::into),
+                Lte => self.builder.build_float_compare(FloatPredicate::OLE, lhs, rhs, "fletmp").map(Into::into),
+                Gt => self.builder.build_float_compare(FloatPredicate::OGT, lhs, rhs, "fgttmp").map(Into::into),
+                Gte => self.builder.build_float_compare(FloatPredicate::OGE, lhs, rhs, "fgetmp").map(Into::into),
+                Modulo => self.builder.build_float_rem(lhs, rhs, "fremtmp").map(Into::into),
+                And | Or => return Err(LlvmBackendError("'&&'/'||' are not defined on floats".to_string())),
+            };
+            result.map_err(|e| LlvmBackendError(e.to_string()))
+        } else {
+            let lhs = lhs.into_int_value();
+            let rhs = rhs.into_int_value();
+            use BinaryOperator::*;
+            let result = match op {
+                Plus => self.builder.build_int_add(lhs, rhs, "addtmp").map(Into::into),
+                Minus => self.builder.build_int_sub(lhs, rhs, "subtmp").map(Into::into),
+                Multiply => self.builder.build_int_mul(lhs, rhs, "multmp").map(Into::into),
+                Divide => self.builder.build_int_signed_div(lhs, rhs, "divtmp").map(Into::into),
+                Modulo => self.builder.build_int_signed_rem(lhs, rhs, "remtmp").map(Into::into),
+                Eq => self.builder.build_int_compare(IntPredicate::EQ, lhs, rhs, "eqtmp").map(Into::into),
+                NotEq => self.builder.build_int_compare(IntPredicate::NE, lhs, rhs, "netmp").map(Into::into),
+                Lt => self.builder.build_int_compare(IntPredicate::SLT, lhs, rhs, "lttmp").map(Into::into),
+                Lte => self.builder.build_int_compare(IntPredicate::SLE, lhs, rhs, "letmp").map(Into::into),
+                Gt => self.builder.build_int_compare(IntPredicate::SGT, lhs, rhs, "gttmp").map(Into::into),
+                Gte => self.builder.build_int_compare(IntPredicate::SGE, lhs, rhs, "getmp").map(Into::into),
+                And => self.builder.build_and(lhs, rhs, "andtmp").map(Into::into),
+                Or => self.builder.build_or(lhs, rhs, "ortmp").map(Into::into),
+            };
+            result.map_err(|e| LlvmBackendError(e.to_string()))
+        }
+    }
+
+    /// LLVM type for one of typeck's concrete `Type`s. `int` -> `i64`, `float`
+    /// -> `f64`; arrays/strings aren't reachable here yet (see module docs).
+    fn map_type(&self, ty: &Type) -> BasicTypeEnum<'ctx> {
+        match ty {
+            Type::Int => self.context.i64_type().into(),
+            Type::Float => self.context.f64_type().into(),
+            Type::Bool => self.context.bool_type().into(),
+            Type::String | Type::Array(_) | Type::Var(_) => self.context.i64_type().into(),
+        }
+    }
+}
+
+fn declare_printf<'ctx>(context: &'ctx Context, module: &Module<'ctx>) -> FunctionValue<'ctx> {
+    let i32_type = context.i32_type();
+    let i8_ptr_type = context.ptr_type(inkwell::AddressSpace::default());
+    let printf_type = i32_type.fn_type(&[i8_ptr_type.into()], true);
+    module.add_function("printf", printf_type, None)
+}
+
+/// Type-checks and compiles `program` straight to the native object file at
+/// `output_path`, bypassing the Rust source round-trip.
+pub fn compile_to_object(program: &Program, types: &TypeckResult, output_path: &Path) -> Result<(), LlvmBackendError> {
+    let context = Context::create();
+    let mut backend = LlvmBackend::new(&context, "zeno_module", types);
+    backend.compile_program(program)?;
+    backend.write_object_file(output_path)
+}
@@ -1,19 +1,90 @@
+mod bench;
+mod diagnostics;
+mod test_runner;
+
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
-use clap::Parser as ClapParser; // Alias to avoid conflict if we have our own Parser
+use clap::{Args as ClapArgs, Parser as ClapParser, Subcommand, ValueEnum}; // Alias to avoid conflict if we have our own Parser
 
 // Assuming your library crate is named 'zeno' (check Cargo.toml)
 // and it exposes the lexer, parser, and generator.
+use zeno::cache::{BuildCache, CacheKey};
 use zeno::lexer::Lexer;
 use zeno::parser::Parser; // Your actual parser struct
 use zeno::generator; // Assuming a generate function like generator::generate()
 use zeno::ast::Program; // Assuming Program is the root AST node
+use zeno::optimize;
+
+/// Which backend `--target` selects. Mirrors `generator::Target`, but as its
+/// own CLI-facing enum so the C/JS variants only show up in `--help` (and
+/// only parse) when the matching cargo feature is actually compiled in.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum CliTarget {
+    Rust,
+    #[cfg(feature = "c-backend")]
+    C,
+    #[cfg(feature = "js-backend")]
+    Js,
+}
+
+impl From<CliTarget> for generator::Target {
+    fn from(target: CliTarget) -> Self {
+        match target {
+            CliTarget::Rust => generator::Target::Rust,
+            #[cfg(feature = "c-backend")]
+            CliTarget::C => generator::Target::C,
+            #[cfg(feature = "js-backend")]
+            CliTarget::Js => generator::Target::Js,
+        }
+    }
+}
+
+/// Which `optimize::OptimizationLevel` `--optimize` selects. A separate
+/// CLI-facing enum for the same reason as `CliTarget`: clap's `ValueEnum`
+/// derive needs to own the type it renders into `--help`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum CliOptLevel {
+    #[default]
+    None,
+    Simple,
+    Full,
+}
+
+impl From<CliOptLevel> for optimize::OptimizationLevel {
+    fn from(level: CliOptLevel) -> Self {
+        match level {
+            CliOptLevel::None => optimize::OptimizationLevel::None,
+            CliOptLevel::Simple => optimize::OptimizationLevel::Simple,
+            CliOptLevel::Full => optimize::OptimizationLevel::Full,
+        }
+    }
+}
+
+/// `-C opt-level` passed to rustc for a `--compile` build. Part of the
+/// build cache's digest, so bumping this invalidates cached executables.
+const RUSTC_OPT_LEVEL: &str = "2";
 
 #[derive(ClapParser, Debug)]
 #[command(author, version, about, long_about = None)]
-struct Args {
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand, Debug)]
+enum Commands {
+    /// Compile (and optionally run) a Zeno source file
+    Compile(CompileArgs),
+    /// Extract and run expected-output tests embedded in .zeno sources under a directory
+    Test(TestArgs),
+    /// Profile lexer, parser, and codegen throughput over a corpus of .zeno files
+    Bench(BenchArgs),
+}
+
+#[derive(ClapArgs, Debug)]
+struct CompileArgs {
     /// Zeno source file to compile
     #[arg(required = true)]
     source_file: PathBuf,
@@ -22,10 +93,18 @@ struct Args {
     #[arg(short, long)]
     output_rust_file: Option<PathBuf>,
 
+    /// Backend to lower the Zeno source to
+    #[arg(long, value_enum, default_value = "rust")]
+    target: CliTarget,
+
+    /// Post-parse optimization level applied to the AST before codegen
+    #[arg(long, value_enum, default_value = "none")]
+    optimize: CliOptLevel,
+
     /// Output file for the compiled executable
     #[arg(short = 'O', long)] // Changed from -o to -O to avoid conflict with -o for rust file
     output_executable_file: Option<PathBuf>,
-    
+
     /// Compile the generated Rust code using rustc
     #[arg(short, long)]
     compile: bool,
@@ -37,15 +116,126 @@ struct Args {
     /// Keep the generated .rs file (default: false, delete if not specified)
     #[arg(short, long)]
     keep_rs: bool,
+
+    /// Skip the build cache and always regenerate/recompile
+    #[arg(long)]
+    no_cache: bool,
+
+    /// Directory for the build cache (default: $XDG_CACHE_HOME/zeno)
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+}
+
+#[derive(ClapArgs, Debug)]
+struct TestArgs {
+    /// Directory to recursively search for .zeno test files
+    dir: PathBuf,
+
+    /// Number of tests to compile and run in parallel
+    #[arg(long, default_value_t = 1)]
+    jobs: usize,
+}
+
+#[derive(ClapArgs, Debug)]
+struct BenchArgs {
+    /// Directory to recursively search for .zeno files to benchmark
+    dir: PathBuf,
+
+    /// Timed iterations per phase per file (the minimum observed is reported)
+    #[arg(long, default_value_t = 10)]
+    iterations: usize,
+
+    /// Untimed iterations per phase per file, run first to stabilize timings
+    #[arg(long, default_value_t = 2)]
+    warmup: usize,
+
+    /// Number of slowest files to report per phase
+    #[arg(long, default_value_t = 5)]
+    top: usize,
+
+    /// Print a machine-readable JSON report instead of a table
+    #[arg(long)]
+    json: bool,
 }
 
 fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
+    let cli = Cli::parse();
+    match cli.command {
+        Commands::Compile(args) => run_compile(args),
+        Commands::Test(args) => run_tests(args),
+        Commands::Bench(args) => run_bench(args),
+    }
+}
+
+fn run_tests(args: TestArgs) -> anyhow::Result<()> {
+    let (passed, failed) = test_runner::run(&args.dir, args.jobs.max(1))?;
+    if failed > 0 {
+        Err(anyhow::anyhow!("{failed} of {} test(s) failed", passed + failed))
+    } else {
+        Ok(())
+    }
+}
+
+fn run_bench(args: BenchArgs) -> anyhow::Result<()> {
+    let report = bench::run(&args.dir, args.iterations, args.warmup)
+        .map_err(|e| anyhow::anyhow!("Failed to run benchmarks over '{}': {}", args.dir.display(), e))?;
+    bench::print_report(&report, args.top, args.json);
+    Ok(())
+}
+
+fn run_compile(args: CompileArgs) -> anyhow::Result<()> {
+    if args.compile && args.target != CliTarget::Rust {
+        return Err(anyhow::anyhow!("--compile/--run invoke rustc and only support --target rust"));
+    }
 
     // 1. Read Zeno source file
     let source_code = fs::read_to_string(&args.source_file)
         .map_err(|e| anyhow::anyhow!("Failed to read source file '{}': {}", args.source_file.display(), e))?;
 
+    // Determine output paths up front: both are needed to serve a cache
+    // hit, which skips parsing, codegen, and rustc entirely.
+    let rust_output_path = args.output_rust_file.clone().unwrap_or_else(|| {
+        args.source_file.with_extension("rs")
+    });
+    let executable_path = args.output_executable_file.clone().unwrap_or_else(|| {
+        // Use source file stem for executable name if not provided
+        let mut exe_name = args.source_file.file_stem().unwrap_or_default().to_os_string();
+        if cfg!(windows) {
+            exe_name.push(".exe");
+        }
+        args.source_file.with_file_name(exe_name)
+    });
+
+    if args.compile && !args.no_cache {
+        let build_cache = BuildCache::new(args.cache_dir.clone().unwrap_or_else(BuildCache::default_dir));
+        let executable_stem = executable_path.file_stem().unwrap_or_default().to_string_lossy().into_owned();
+        let digest = CacheKey {
+            source: &source_code,
+            executable_stem: &executable_stem,
+            opt_level: RUSTC_OPT_LEVEL,
+            ast_optimize_level: &format!("{:?}", args.optimize),
+        }
+        .digest();
+
+        if let Some(entry) = build_cache.lookup(&digest) {
+            println!("Build cache hit ({digest}); reusing previous executable.");
+            fs::copy(entry.rust_source_path(), &rust_output_path)
+                .map_err(|e| anyhow::anyhow!("Failed to copy cached Rust source to '{}': {}", rust_output_path.display(), e))?;
+            fs::copy(entry.executable_path(), &executable_path)
+                .map_err(|e| anyhow::anyhow!("Failed to copy cached executable to '{}': {}", executable_path.display(), e))?;
+
+            if args.run {
+                run_executable(&executable_path)?;
+            }
+            if !args.keep_rs {
+                fs::remove_file(&rust_output_path)
+                    .map_err(|e| anyhow::anyhow!("Failed to delete temporary .rs file '{}': {}", rust_output_path.display(), e))?;
+                println!("Removed temporary Rust file: {}", rust_output_path.display());
+            }
+            return Ok(());
+        }
+    }
+
     // 2. Lexing
     // The Lexer in this project is an iterator.
     // For the parser, we re-initialize the lexer as it consumes the input.
@@ -53,45 +243,44 @@ fn main() -> anyhow::Result<()> {
     // The parser will call next_token() on its own lexer instance.
 
     // 3. Parsing
-    let mut parser_lexer = Lexer::new(&source_code); 
-    let mut parser = Parser::new(parser_lexer); 
+    let parser_lexer = Lexer::new(&source_code);
+    let mut parser = Parser::new(parser_lexer);
     let ast: Program = match parser.parse_program() {
         Ok(program) => program,
         Err(errors) => {
             eprintln!("Encountered parsing errors:");
-            for error in errors {
+            for error in &errors {
                 eprintln!("  - {}", error);
             }
-            return Err(anyhow::anyhow!("Parsing failed with {} error(s).", parser.errors.len()));
+            return Err(anyhow::anyhow!("Parsing failed with {} error(s).", errors.len()));
         }
     };
 
-    // 4. Code Generation
-    let rust_code = generator::generate(&ast)
-        .map_err(|e| anyhow::anyhow!("Code generation failed: {}", e))?; // GenerationError impls Display
+    // 3.5. Optimization (a no-op pass at the default "none" level)
+    let ast = optimize::optimize(ast, args.optimize.into());
 
-    // 5. Determine output Rust file path
-    let rust_output_path = args.output_rust_file.clone().unwrap_or_else(|| {
-        args.source_file.with_extension("rs")
-    });
+    // 4. Code Generation
+    // When we're about to compile, also build the source map so a failed
+    // rustc invocation can be reported against the Zeno source instead of
+    // the generated .rs file's own (meaningless to the user) line numbers.
+    let (rust_code, source_map) = if args.compile {
+        generator::generate_with_source_map(&ast)
+            .map_err(|e| anyhow::anyhow!("Code generation failed: {}", e))?
+    } else {
+        (
+            generator::generate_for(&ast, args.target.into())
+                .map_err(|e| anyhow::anyhow!("Code generation failed: {}", e))?,
+            Vec::new(),
+        )
+    };
 
     fs::write(&rust_output_path, &rust_code)
         .map_err(|e| anyhow::anyhow!("Failed to write generated Rust code to '{}': {}", rust_output_path.display(), e))?;
-    
+
     println!("Generated Rust code written to: {}", rust_output_path.display());
 
     if args.compile {
         // 6. Compile generated Rust code
-        let executable_path = args.output_executable_file.clone().unwrap_or_else(|| {
-            // Use source file stem for executable name if not provided
-            let mut exe_name = args.source_file.file_stem().unwrap_or_default().to_os_string();
-            if cfg!(windows) {
-                exe_name.push(".exe");
-            }
-            args.source_file.with_file_name(exe_name)
-        });
-
-
         println!("Compiling generated Rust code with rustc...");
         let mut command = Command::new("rustc");
         command.arg(&rust_output_path);
@@ -100,36 +289,42 @@ fn main() -> anyhow::Result<()> {
         
         // Add optimization flags for release-like build
         command.arg("-C");
-        command.arg("opt-level=2");
+        command.arg(format!("opt-level={RUSTC_OPT_LEVEL}"));
 
+        // Structured diagnostics, so failures can be remapped to Zeno
+        // source positions instead of dumped as raw rustc text.
+        command.arg("--error-format=json");
 
         let output = command.output() // Use output() to capture stderr for better error reporting
             .map_err(|e| anyhow::anyhow!("Failed to execute rustc: {}", e))?;
 
         if !output.status.success() {
-            eprintln!("rustc compilation failed.");
-            eprintln!("--- rustc STDOUT ---");
-            eprintln!("{}", String::from_utf8_lossy(&output.stdout));
-            eprintln!("--- rustc STDERR ---");
-            eprintln!("{}", String::from_utf8_lossy(&output.stderr));
-            return Err(anyhow::anyhow!("rustc compilation failed. Status: {}", output.status));
+            eprintln!("rustc compilation failed:");
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let error_count = diagnostics::report(&stderr, &source_map, &args.source_file);
+            return Err(anyhow::anyhow!("rustc compilation failed with {error_count} error(s)."));
         }
         println!("Compilation successful. Executable at: {}", executable_path.display());
 
-        if args.run {
-            // 7. Run compiled executable
-            println!("Running executable '{}'...", executable_path.display());
-            let mut run_command = Command::new(&executable_path);
-            let run_status = run_command.status()
-                .map_err(|e| anyhow::anyhow!("Failed to run executable '{}': {}", executable_path.display(), e))?;
-            
-            if !run_status.success() {
-                eprintln!("Executable '{}' exited with error code: {:?}", executable_path.display(), run_status.code());
-            } else {
-                println!("Executable finished successfully.");
+        if !args.no_cache {
+            let build_cache = BuildCache::new(args.cache_dir.clone().unwrap_or_else(BuildCache::default_dir));
+            let executable_stem = executable_path.file_stem().unwrap_or_default().to_string_lossy().into_owned();
+            let digest = CacheKey {
+                source: &source_code,
+                executable_stem: &executable_stem,
+                opt_level: RUSTC_OPT_LEVEL,
+                ast_optimize_level: &format!("{:?}", args.optimize),
+            }
+            .digest();
+            if let Err(e) = build_cache.store(&digest, &rust_code, &executable_path, &args.source_file) {
+                eprintln!("Warning: failed to write build cache entry: {e}");
             }
         }
-        
+
+        if args.run {
+            run_executable(&executable_path)?;
+        }
+
         // Delete the .rs file only if compilation was successful (or attempted) and --keep-rs is not set
         if !args.keep_rs {
              fs::remove_file(&rust_output_path)
@@ -148,3 +343,20 @@ fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Runs a compiled executable, reporting (but not failing on) a non-zero
+/// exit code, since that reflects the Zeno program's own behavior rather
+/// than a problem with the compiler.
+fn run_executable(executable_path: &Path) -> anyhow::Result<()> {
+    println!("Running executable '{}'...", executable_path.display());
+    let mut run_command = Command::new(executable_path);
+    let run_status = run_command.status()
+        .map_err(|e| anyhow::anyhow!("Failed to run executable '{}': {}", executable_path.display(), e))?;
+
+    if !run_status.success() {
+        eprintln!("Executable '{}' exited with error code: {:?}", executable_path.display(), run_status.code());
+    } else {
+        println!("Executable finished successfully.");
+    }
+    Ok(())
+}
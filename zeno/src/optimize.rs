@@ -0,0 +1,283 @@
+use crate::ast::{BinaryOperator, Block, Expr, Program, Statement, UnaryOperator};
+
+/// How aggressively `optimize` simplifies a parsed `Program`, in the style
+/// of `-O0`/`-O1`/`-O2`: each level is a strict superset of the passes
+/// below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptimizationLevel {
+    /// `optimize` returns the program unchanged.
+    None,
+    /// Constant-fold pure expressions (`1 + 2` -> `3`).
+    Simple,
+    /// Everything `Simple` does, plus dropping statements whose value is
+    /// both pure and discarded.
+    Full,
+}
+
+/// Runs the requested optimization passes over a parsed `Program`. Meant as
+/// a post-parse step before typeck/codegen/interpretation, not a parser
+/// concern itself -- it only ever simplifies an already-valid AST into an
+/// equivalent one, never changes what it means.
+///
+/// A block's trailing un-terminated expression (`Block::result` /
+/// `Program::result`) is never a candidate for removal: the parser already
+/// separates "the value a block produces" from "statements whose value is
+/// thrown away" by hoisting exactly that one expression out of
+/// `statements` (see `Parser::parse_block_statement`), so anything left
+/// over as an ordinary `Statement::ExprStatement` was explicitly
+/// terminated with `;` and really is dead if it's pure.
+pub fn optimize(program: Program, level: OptimizationLevel) -> Program {
+    if level == OptimizationLevel::None {
+        return program;
+    }
+    let Program { statements, result, statement_spans } = program;
+    let (statements, statement_spans) = statements
+        .into_iter()
+        .zip(statement_spans)
+        .map(|(stmt, span)| (optimize_statement(stmt, level), span))
+        .filter(|(stmt, _)| !(level == OptimizationLevel::Full && is_dead_pure_statement(stmt)))
+        .unzip();
+    Program { statements, result: result.map(fold_expr), statement_spans }
+}
+
+fn optimize_block(block: Block, level: OptimizationLevel) -> Block {
+    let Block { statements, result } = block;
+    let statements = statements
+        .into_iter()
+        .map(|stmt| optimize_statement(stmt, level))
+        .filter(|stmt| !(level == OptimizationLevel::Full && is_dead_pure_statement(stmt)))
+        .collect();
+    Block { statements, result: result.map(fold_expr) }
+}
+
+fn is_dead_pure_statement(stmt: &Statement) -> bool {
+    matches!(stmt, Statement::ExprStatement { expr } if is_pure_expr(expr))
+}
+
+fn optimize_statement(stmt: Statement, level: OptimizationLevel) -> Statement {
+    match stmt {
+        Statement::LetDecl { name, type_ann, mutable, value_expr } => {
+            Statement::LetDecl { name, type_ann, mutable, value_expr: fold_expr(value_expr) }
+        }
+        Statement::Assignment { target, value_expr } => {
+            Statement::Assignment { target: fold_expr(target), value_expr: fold_expr(value_expr) }
+        }
+        Statement::ExprStatement { expr } => Statement::ExprStatement { expr: fold_expr(expr) },
+        Statement::Print { expr, newline } => Statement::Print { expr: fold_expr(expr), newline },
+        Statement::If { condition, then_block, else_if_blocks, else_block } => Statement::If {
+            condition: fold_expr(condition),
+            then_block: optimize_block(then_block, level),
+            else_if_blocks: else_if_blocks
+                .into_iter()
+                .map(|(cond, block)| (fold_expr(cond), optimize_block(block, level)))
+                .collect(),
+            else_block: else_block.map(|block| optimize_block(block, level)),
+        },
+        Statement::While { condition, body_block } => {
+            Statement::While { condition: fold_expr(condition), body_block: optimize_block(body_block, level) }
+        }
+        Statement::Loop { body_block } => Statement::Loop { body_block: optimize_block(body_block, level) },
+        Statement::For { initializer, condition, increment, body_block } => Statement::For {
+            initializer: initializer.map(|stmt| Box::new(optimize_statement(*stmt, level))),
+            condition: condition.map(fold_expr),
+            increment: increment.map(|stmt| Box::new(optimize_statement(*stmt, level))),
+            body_block: optimize_block(body_block, level),
+        },
+        Statement::Break => Statement::Break,
+        Statement::Continue => Statement::Continue,
+        Statement::FnDecl { name, params, return_type, body } => {
+            Statement::FnDecl { name, params, return_type, body: optimize_block(body, level) }
+        }
+        Statement::Return { expr } => Statement::Return { expr: expr.map(fold_expr) },
+    }
+}
+
+/// Whether evaluating `expr` could have any effect other than producing its
+/// value -- i.e. whether a statement consisting only of `expr` and nothing
+/// else is safe to drop. Conservative: a call's target is unknown here (it
+/// could be `print`-like, or anything a user defines), so any `Call` is
+/// treated as impure regardless of its arguments.
+fn is_pure_expr(expr: &Expr) -> bool {
+    match expr {
+        Expr::Integer(_) | Expr::Float(_) | Expr::StringLiteral(_) | Expr::Boolean(_) | Expr::Identifier(_) | Expr::OperatorFn(_) => true,
+        Expr::BinaryOp { left, right, .. } => is_pure_expr(left) && is_pure_expr(right),
+        Expr::UnaryOp { expr, .. } => is_pure_expr(expr),
+        Expr::ArrayLiteral(elements) => elements.iter().all(is_pure_expr),
+        Expr::Map(entries) => entries.iter().all(|(_, value)| is_pure_expr(value)),
+        Expr::Index { target, index } => is_pure_expr(target) && is_pure_expr(index),
+        Expr::Member { target, .. } => is_pure_expr(target),
+        Expr::Call { .. } => false,
+    }
+}
+
+/// Recursively constant-folds `expr`, mirroring exactly the operand/result
+/// combinations `interpreter::eval_binary_op` accepts at runtime -- a
+/// combination that isn't a match there (e.g. integer division by zero, or
+/// operand types with no defined operator) is left unfolded rather than
+/// guessed at, so it still fails the same way at runtime it would have
+/// unoptimized.
+fn fold_expr(expr: Expr) -> Expr {
+    match expr {
+        Expr::BinaryOp { left, op, right } => {
+            let left = fold_expr(*left);
+            let right = fold_expr(*right);
+            match fold_binary_op(&op, &left, &right) {
+                Some(folded) => folded,
+                None => Expr::BinaryOp { left: Box::new(left), op, right: Box::new(right) },
+            }
+        }
+        Expr::UnaryOp { op, expr } => {
+            let expr = fold_expr(*expr);
+            match fold_unary_op(&op, &expr) {
+                Some(folded) => folded,
+                None => Expr::UnaryOp { op, expr: Box::new(expr) },
+            }
+        }
+        Expr::Call { callee, args } => {
+            Expr::Call { callee: Box::new(fold_expr(*callee)), args: args.into_iter().map(fold_expr).collect() }
+        }
+        Expr::Member { target, field } => Expr::Member { target: Box::new(fold_expr(*target)), field },
+        Expr::ArrayLiteral(elements) => Expr::ArrayLiteral(elements.into_iter().map(fold_expr).collect()),
+        Expr::Map(entries) => Expr::Map(entries.into_iter().map(|(key, value)| (key, fold_expr(value))).collect()),
+        Expr::Index { target, index } => {
+            Expr::Index { target: Box::new(fold_expr(*target)), index: Box::new(fold_expr(*index)) }
+        }
+        leaf @ (Expr::Integer(_) | Expr::Float(_) | Expr::StringLiteral(_) | Expr::Boolean(_) | Expr::Identifier(_) | Expr::OperatorFn(_)) => leaf,
+    }
+}
+
+fn fold_binary_op(op: &BinaryOperator, left: &Expr, right: &Expr) -> Option<Expr> {
+    use BinaryOperator::*;
+    match (op, left, right) {
+        (Plus, Expr::Integer(a), Expr::Integer(b)) => Some(Expr::Integer(a + b)),
+        (Minus, Expr::Integer(a), Expr::Integer(b)) => Some(Expr::Integer(a - b)),
+        (Multiply, Expr::Integer(a), Expr::Integer(b)) => Some(Expr::Integer(a * b)),
+        (Divide, Expr::Integer(a), Expr::Integer(b)) if *b != 0 => Some(Expr::Integer(a / b)),
+        (Modulo, Expr::Integer(a), Expr::Integer(b)) if *b != 0 => Some(Expr::Integer(a % b)),
+        (Plus, Expr::Float(a), Expr::Float(b)) => Some(Expr::Float(a + b)),
+        (Minus, Expr::Float(a), Expr::Float(b)) => Some(Expr::Float(a - b)),
+        (Multiply, Expr::Float(a), Expr::Float(b)) => Some(Expr::Float(a * b)),
+        (Divide, Expr::Float(a), Expr::Float(b)) => Some(Expr::Float(a / b)),
+        (Plus, Expr::StringLiteral(a), Expr::StringLiteral(b)) => Some(Expr::StringLiteral(format!("{a}{b}"))),
+        (Eq, Expr::Integer(a), Expr::Integer(b)) => Some(Expr::Boolean(a == b)),
+        (NotEq, Expr::Integer(a), Expr::Integer(b)) => Some(Expr::Boolean(a != b)),
+        (Lt, Expr::Integer(a), Expr::Integer(b)) => Some(Expr::Boolean(a < b)),
+        (Lte, Expr::Integer(a), Expr::Integer(b)) => Some(Expr::Boolean(a <= b)),
+        (Gt, Expr::Integer(a), Expr::Integer(b)) => Some(Expr::Boolean(a > b)),
+        (Gte, Expr::Integer(a), Expr::Integer(b)) => Some(Expr::Boolean(a >= b)),
+        (Eq, Expr::Float(a), Expr::Float(b)) => Some(Expr::Boolean(a == b)),
+        (NotEq, Expr::Float(a), Expr::Float(b)) => Some(Expr::Boolean(a != b)),
+        (Lt, Expr::Float(a), Expr::Float(b)) => Some(Expr::Boolean(a < b)),
+        (Lte, Expr::Float(a), Expr::Float(b)) => Some(Expr::Boolean(a <= b)),
+        (Gt, Expr::Float(a), Expr::Float(b)) => Some(Expr::Boolean(a > b)),
+        (Gte, Expr::Float(a), Expr::Float(b)) => Some(Expr::Boolean(a >= b)),
+        (Eq, Expr::Boolean(a), Expr::Boolean(b)) => Some(Expr::Boolean(a == b)),
+        (NotEq, Expr::Boolean(a), Expr::Boolean(b)) => Some(Expr::Boolean(a != b)),
+        (And, Expr::Boolean(a), Expr::Boolean(b)) => Some(Expr::Boolean(*a && *b)),
+        (Or, Expr::Boolean(a), Expr::Boolean(b)) => Some(Expr::Boolean(*a || *b)),
+        _ => None,
+    }
+}
+
+fn fold_unary_op(op: &UnaryOperator, expr: &Expr) -> Option<Expr> {
+    match (op, expr) {
+        (UnaryOperator::Negate, Expr::Integer(n)) => Some(Expr::Integer(-n)),
+        (UnaryOperator::Negate, Expr::Float(n)) => Some(Expr::Float(-n)),
+        (UnaryOperator::Not, Expr::Boolean(b)) => Some(Expr::Boolean(!b)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Lexer;
+    use crate::parser::Parser;
+
+    fn optimized(input: &str, level: OptimizationLevel) -> Program {
+        let mut parser = Parser::new(Lexer::new(input));
+        let program = parser.parse_program().expect("input should parse without errors");
+        optimize(program, level)
+    }
+
+    #[test]
+    fn none_leaves_the_program_untouched() {
+        let program = optimized("let x = 1 + 2;", OptimizationLevel::None);
+        assert_eq!(program.statements[0], Statement::LetDecl {
+            name: "x".to_string(),
+            type_ann: None,
+            mutable: false,
+            value_expr: Expr::BinaryOp { left: Box::new(Expr::Integer(1)), op: BinaryOperator::Plus, right: Box::new(Expr::Integer(2)) },
+        });
+    }
+
+    #[test]
+    fn simple_folds_constant_arithmetic() {
+        let program = optimized("let x = 1 + 2 * 3;", OptimizationLevel::Simple);
+        assert_eq!(program.statements[0], Statement::LetDecl {
+            name: "x".to_string(),
+            type_ann: None,
+            mutable: false,
+            value_expr: Expr::Integer(7),
+        });
+    }
+
+    #[test]
+    fn simple_does_not_fold_division_by_zero() {
+        // Folding this would change "fails at runtime" into "fails at
+        // compile time with a different, optimizer-only error path" --
+        // left alone so it still fails exactly the way it would have
+        // unoptimized.
+        let program = optimized("print(1 / 0);", OptimizationLevel::Simple);
+        assert_eq!(program.statements[0], Statement::Print {
+            expr: Expr::BinaryOp { left: Box::new(Expr::Integer(1)), op: BinaryOperator::Divide, right: Box::new(Expr::Integer(0)) },
+            newline: false,
+        });
+    }
+
+    #[test]
+    fn full_removes_dead_pure_statement_mid_block_but_keeps_side_effects() {
+        let program = optimized("if true { 5 + 2; print(1); }", OptimizationLevel::Full);
+        match &program.statements[0] {
+            Statement::If { then_block, .. } => {
+                assert_eq!(then_block.statements.len(), 1, "the dead `5 + 2;` should have been dropped");
+                assert_eq!(then_block.statements[0], Statement::Print { expr: Expr::Integer(1), newline: false });
+            }
+            other => panic!("expected an If statement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn full_preserves_a_blocks_trailing_result_expression() {
+        // No ';' after `5 + 2`, so the parser already hoists it into
+        // `then_block.result` rather than `then_block.statements` -- it's
+        // never a dead-statement candidate to begin with.
+        let program = optimized("if true { print(1); 5 + 2 }", OptimizationLevel::Full);
+        match &program.statements[0] {
+            Statement::If { then_block, .. } => {
+                assert_eq!(then_block.statements.len(), 1);
+                assert_eq!(then_block.result, Some(Expr::Integer(7)), "the folded trailing value should survive");
+            }
+            other => panic!("expected an If statement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn full_keeps_assignments_and_let_decls_even_when_unused() {
+        let program = optimized("let x = 1; x = 2;", OptimizationLevel::Full);
+        assert_eq!(program.statements.len(), 2, "assignments are never dead code, even to an otherwise-unread variable");
+    }
+
+    #[test]
+    fn collapses_runs_of_empty_statements() {
+        // A bare ';' is already dropped by the parser itself (see
+        // `Parser::parse_program`'s `None => self.synchronize()` arm), so
+        // a run of them between real statements contributes nothing for
+        // `optimize` to even see -- this holds at every level, not just
+        // `Full`, since there's no dead statement left to remove.
+        for level in [OptimizationLevel::None, OptimizationLevel::Simple, OptimizationLevel::Full] {
+            let program = optimized("let x = 1;;; print(x);", level);
+            assert_eq!(program.statements.len(), 2, "the ';;;' run should contribute zero statements at {:?}", level);
+        }
+    }
+}
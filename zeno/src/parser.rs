@@ -1,9 +1,95 @@
 use crate::ast::*;
-use crate::lexer::{Lexer, Token}; // Token is already imported
+use crate::lexer::{Lexer, Position, Token}; // Token is already imported
 
 use std::collections::HashMap;
 use std::sync::LazyLock; // Import LazyLock
 
+/// A single parse failure, mirroring the `LexError` pattern: one variant
+/// per distinct failure kind, each carrying the data (and `Position`)
+/// needed to describe it, so a caller can match on the kind of failure
+/// instead of scraping a message string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// `expect_peek`/`peek_error`-style failures: a specific token was
+    /// required at this position and a different one was found.
+    UnexpectedToken { expected: Token, found: Token, position: Position },
+    /// A `parse_expression` call that was required to produce a value
+    /// (the right side of `=`, a `return`'s expr, a call argument, ...)
+    /// returned `None`.
+    MissingExpression { position: Position },
+    /// A `Token::Float`'s text didn't parse as an `f64`.
+    InvalidFloatLiteral(String, Position),
+    /// `parse_expression`'s prefix dispatch had no handler for this token.
+    UnknownPrefixOperator(Token, Position),
+    /// `parse_infix_expression` was invoked for a token that isn't a known
+    /// binary operator.
+    UnknownInfixOperator(Token, Position),
+    /// Catch-all for failures that don't fit the shapes above (an invalid
+    /// assignment target, a malformed call callee, an internal dispatch
+    /// invariant violation), keeping the position and offending token for
+    /// consistency with every other variant.
+    Other { message: String, found: Token, position: Position },
+    /// A statement finished but the following token can neither continue
+    /// it nor start a new statement -- almost always a missing `;`.
+    /// Unlike every other variant, `position` is anchored at the *end* of
+    /// the statement's last token rather than at the offending token, so
+    /// the diagnostic points at the gap where the `;` belongs instead of
+    /// the (often unrelated-looking) token that follows it. `suggestion`
+    /// is the literal text a caller can splice in at `position` to fix it.
+    MissingSemicolon { position: Position, suggestion: String },
+    /// An `if`/`else`/`else if`/`for`/`while`/`loop` body wasn't wrapped in
+    /// `{ }`. `header_position` is where the construct's keyword (or, for
+    /// `if`/`while`/`else if`, its condition) started -- reported as a
+    /// secondary note so the reader can see what was actually parsed as
+    /// the header -- while `body_position` is where recovery resumed,
+    /// treating the single following statement as a one-statement body.
+    MissingBraces { construct: &'static str, header_position: Position, body_position: Position },
+    /// A `(`/`{`/`[` was never matched by its closing delimiter before the
+    /// parse gave up. `delimiter` is the open token itself and `position`
+    /// is where it was opened. Reported in place of whatever secondary
+    /// error the resulting parse failure would otherwise have produced --
+    /// see `Parser::push_secondary_error`.
+    UnclosedDelimiter { delimiter: Token, position: Position },
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::UnexpectedToken { expected, found, position } => {
+                write!(f, "Parse Error: expected {:?}, found {:?} at {}", expected, found, position)
+            }
+            ParseError::MissingExpression { position } => {
+                write!(f, "Parse Error: expected an expression at {}", position)
+            }
+            ParseError::InvalidFloatLiteral(text, position) => {
+                write!(f, "Parse Error: could not parse float literal '{}' at {}", text, position)
+            }
+            ParseError::UnknownPrefixOperator(token, position) => {
+                write!(f, "Parse Error: no prefix parse function for {:?} at {}", token, position)
+            }
+            ParseError::UnknownInfixOperator(token, position) => {
+                write!(f, "Parse Error: unknown infix operator {:?} at {}", token, position)
+            }
+            ParseError::Other { message, position, .. } => write!(f, "Parse Error: {} at {}", message, position),
+            ParseError::MissingSemicolon { position, suggestion } => {
+                write!(f, "Parse Error: expected `;` at {} (try: {})", position, suggestion)
+            }
+            ParseError::MissingBraces { construct, header_position, body_position } => {
+                write!(
+                    f,
+                    "Parse Error: the body of `{}` must be surrounded by braces at {} (note: this is the parsed {} at {})",
+                    construct, body_position, construct, header_position
+                )
+            }
+            ParseError::UnclosedDelimiter { delimiter, position } => {
+                write!(f, "Parse Error: unclosed delimiter {:?} at {}", delimiter, position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 #[derive(PartialEq, PartialOrd, Debug, Clone, Copy)]
 enum Precedence {
     LOWEST,
@@ -39,6 +125,8 @@ static PRECEDENCES: LazyLock<HashMap<Token, Precedence>> = LazyLock::new(|| {
     m.insert(Token::Divide, Precedence::PRODUCT);
     m.insert(Token::Modulo, Precedence::PRODUCT);
     m.insert(Token::LParen, Precedence::CALL); // For call expressions like func()
+    m.insert(Token::LBracket, Precedence::CALL); // For index expressions like arr[i]
+    m.insert(Token::Dot, Precedence::CALL); // For member access like obj.field
     // Prefix operators like Token::Bang (!) and Token::Minus (-) for prefix are handled by their parsing functions,
     // not by infix precedence lookup here.
     m
@@ -48,20 +136,71 @@ fn token_precedence(token: &Token) -> Precedence {
     PRECEDENCES.get(token).cloned().unwrap_or(Precedence::LOWEST)
 }
 
+/// Whether `expr` is a valid assignment target: a bare identifier or an
+/// index expression (`arr[i]`). Anything else -- literals, calls, binary
+/// ops, etc. -- can't appear on the left of `=` or a compound assignment.
+fn is_assignable_target(expr: &Expr) -> bool {
+    matches!(expr, Expr::Identifier(_) | Expr::Index { .. })
+}
+
 pub struct Parser<'a> {
     lexer: Lexer<'a>,
     current_token: Token,
+    current_pos: Position,
+    /// Where `current_token` ends, i.e. the position right after its last
+    /// character. Used only for diagnostics that need to anchor at "the
+    /// end of the previous token" (`ParseError::MissingSemicolon`) rather
+    /// than at the start of the token that follows it.
+    current_end_pos: Position,
     peek_token: Token,
-    errors: Vec<String>,
+    peek_pos: Position,
+    peek_end_pos: Position,
+    errors: Vec<ParseError>,
+    /// Every `(`/`{`/`[` opened by an expression/block construct (grouped
+    /// expressions, call args, array/map literals, index expressions,
+    /// blocks, `print`'s parens) that hasn't yet been matched by its
+    /// closing delimiter, along with where it was opened and whether its
+    /// own "unclosed" diagnostic has already been recorded. While this is
+    /// non-empty, a secondary error raised through `push_secondary_error`
+    /// is fallout from the still-open delimiter, not a new problem: the
+    /// first one is replaced by a single `UnclosedDelimiter` pointing at
+    /// the innermost entry (and that entry is marked reported so later
+    /// fallout from the same unwind doesn't report it again). Cleared by
+    /// `synchronize`, since resyncing to the next statement boundary means
+    /// whatever was still "open" no longer is.
+    unclosed_delimiters: Vec<(Token, Position, bool)>,
+    /// Set by `new_repl`. In REPL mode, a trailing expression statement
+    /// with no terminating semicolon becomes `Program::result` instead of
+    /// an ordinary statement, so an interactive shell can print it.
+    repl: bool,
 }
 
 impl<'a> Parser<'a> {
     pub fn new(lexer: Lexer<'a>) -> Self {
+        Self::new_with_repl(lexer, false)
+    }
+
+    /// Like `new`, but following the complexpr parser's `repl` flag
+    /// pattern: a final un-terminated expression statement is kept as
+    /// `Program::result` rather than an ordinary statement, so a REPL can
+    /// echo the value of `1 + 2` typed at the prompt.
+    pub fn new_repl(lexer: Lexer<'a>) -> Self {
+        Self::new_with_repl(lexer, true)
+    }
+
+    fn new_with_repl(lexer: Lexer<'a>, repl: bool) -> Self {
+        let placeholder_pos = Position { line: 1, column: 1 };
         let mut p = Parser {
             lexer,
             current_token: Token::Eof, // Placeholder
-            peek_token: Token::Eof,    // Placeholder
+            current_pos: placeholder_pos,
+            current_end_pos: placeholder_pos,
+            peek_token: Token::Eof, // Placeholder
+            peek_pos: placeholder_pos,
+            peek_end_pos: placeholder_pos,
             errors: Vec::new(),
+            unclosed_delimiters: Vec::new(),
+            repl,
         };
         p.next_token();
         p.next_token();
@@ -70,7 +209,88 @@ impl<'a> Parser<'a> {
 
     fn next_token(&mut self) {
         self.current_token = self.peek_token.clone();
-        self.peek_token = self.lexer.next_token();
+        self.current_pos = self.peek_pos;
+        self.current_end_pos = self.peek_end_pos;
+        let (spanned, lex_error) = self.lexer.next_token_spanned();
+        if let Some(lex_error) = lex_error {
+            self.errors.push(ParseError::Other {
+                message: lex_error.to_string(),
+                found: spanned.token.clone(),
+                position: lex_error.span().into(),
+            });
+        }
+        self.peek_end_pos = Position { line: spanned.span.end_line, column: spanned.span.end_col };
+        self.peek_token = spanned.token;
+        self.peek_pos = spanned.span.into();
+    }
+
+    /// Records a catch-all parse error at the current token's position.
+    /// Covers failures that don't fit one of `ParseError`'s structured
+    /// variants (an invalid assignment target, a malformed call callee, an
+    /// internal dispatch invariant violation); `peek_error` below handles
+    /// the "expected next token" shape separately since that error is about
+    /// `peek_token`, not `current_token`.
+    fn push_error(&mut self, message: String) {
+        self.errors.push(ParseError::Other {
+            message,
+            found: self.current_token.clone(),
+            position: self.current_pos,
+        });
+    }
+
+    /// Records an `UnexpectedToken` error for a required token that was
+    /// expected at `current_token`'s position (as opposed to `peek_error`,
+    /// which is about `peek_token`).
+    fn push_unexpected(&mut self, expected: Token) {
+        self.errors.push(ParseError::UnexpectedToken {
+            expected,
+            found: self.current_token.clone(),
+            position: self.current_pos,
+        });
+    }
+
+    /// Marks `open` (a `(`/`{`/`[`) as not yet closed, recorded at
+    /// `current_token`'s position (every call site invokes this while
+    /// `current_token` still *is* the open delimiter). Paired with
+    /// `exit_delimiter` once its matching close is found.
+    fn enter_delimiter(&mut self, open: Token) {
+        self.unclosed_delimiters.push((open, self.current_pos, false));
+    }
+
+    /// Marks the innermost still-open delimiter as closed.
+    fn exit_delimiter(&mut self) {
+        self.unclosed_delimiters.pop();
+    }
+
+    /// Marks the innermost still-unclosed delimiter as already having
+    /// produced its own "expected closing token" diagnostic (an
+    /// `expect_peek`/`push_unexpected` failure on the matching close),
+    /// so a later `push_secondary_error` call during the same unwind
+    /// suppresses instead of reporting the same delimiter again.
+    fn mark_innermost_delimiter_reported(&mut self) {
+        if let Some(top) = self.unclosed_delimiters.last_mut() {
+            top.2 = true;
+        }
+    }
+
+    /// Records `error` unless an enclosing delimiter is still unclosed. In
+    /// that case `error` itself is just cascading fallout from the failed
+    /// parse the unclosed delimiter triggered: the first time this fires
+    /// for a given unclosed delimiter, it's replaced by a single
+    /// `UnclosedDelimiter` pointing at the innermost one (which is then
+    /// marked reported); every later call while that delimiter is still
+    /// the innermost unclosed one is fully suppressed.
+    fn push_secondary_error(&mut self, error: ParseError) {
+        if let Some(top) = self.unclosed_delimiters.last() {
+            if !top.2 {
+                let delimiter = top.0.clone();
+                let position = top.1;
+                self.errors.push(ParseError::UnclosedDelimiter { delimiter, position });
+                self.mark_innermost_delimiter_reported();
+            }
+            return;
+        }
+        self.errors.push(error);
     }
 
     fn current_token_is(&self, t: &Token) -> bool {
@@ -92,11 +312,11 @@ impl<'a> Parser<'a> {
     }
 
     fn peek_error(&mut self, t: &Token) {
-        let msg = format!(
-            "expected next token to be {:?}, got {:?} instead. (current: {:?})",
-            t, self.peek_token, self.current_token
-        );
-        self.errors.push(msg);
+        self.errors.push(ParseError::UnexpectedToken {
+            expected: t.clone(),
+            found: self.peek_token.clone(),
+            position: self.peek_pos,
+        });
     }
     
     fn current_precedence(&self) -> Precedence {
@@ -107,27 +327,37 @@ impl<'a> Parser<'a> {
         token_precedence(&self.peek_token)
     }
 
-    pub fn parse_program(&mut self) -> Result<Program, Vec<String>> {
-        let mut program = Program { statements: Vec::new() };
+    pub fn parse_program(&mut self) -> Result<Program, Vec<ParseError>> {
+        let mut program = Program { statements: Vec::new(), result: None, statement_spans: Vec::new() };
 
         while !self.current_token_is(&Token::Eof) {
+            let start_pos = self.current_pos;
             match self.parse_statement() {
-                Some(statement) => program.statements.push(statement),
-                None => { 
-                    // If parse_statement returns None, it means a severe error occurred,
-                    // or it was an empty statement (e.g. just ';').
-                    // Errors should have been logged. We can try to recover by advancing.
-                    // However, parse_statement itself should advance tokens.
-                    // This path might indicate we're not at EOF but can't parse a statement.
+                Some(statement) => {
+                    let end_pos = self.current_pos;
+                    // An explicit semicolon right before we advance means
+                    // the statement was terminated on purpose; only a
+                    // bare, un-terminated expression at the very end of
+                    // the program is eligible to become the REPL result.
+                    let had_semicolon = self.current_token_is(&Token::Semicolon);
+                    // Consume the last token of the statement (e.g. ';' or '}').
+                    self.next_token();
+
+                    if self.repl && !had_semicolon && self.current_token_is(&Token::Eof) {
+                        if let Statement::ExprStatement { expr } = statement {
+                            program.result = Some(expr);
+                            continue;
+                        }
+                    }
+                    program.statements.push(statement);
+                    program.statement_spans.push(SourceSpan { start: start_pos, end: end_pos });
                 }
+                // A failed statement (or a genuine empty one, e.g. a bare
+                // ';') resyncs to the next statement boundary instead of
+                // blindly advancing one token, so one bad statement
+                // doesn't cascade into a flood of follow-on errors.
+                None => self.synchronize(),
             }
-            // parse_statement is responsible for consuming all tokens related to the statement,
-            // including the trailing semicolon if applicable.
-            // So, we should *not* call self.next_token() here IF parse_statement does its job.
-            // Let's adjust parse_statement to always advance to the next token that begins a new statement.
-            // For now, the original loop structure where parse_program calls next_token is common:
-             self.next_token(); // Consume the last token of the statement (e.g., ';', '}')
-                                // or the token that caused parse_statement to return None.
         }
 
         if self.errors.is_empty() {
@@ -137,10 +367,102 @@ impl<'a> Parser<'a> {
         }
     }
 
-    pub fn errors(&self) -> &Vec<String> {
+    pub fn errors(&self) -> &Vec<ParseError> {
         &self.errors
     }
 
+    /// Panic-mode recovery (in the style of rustc's parser), invoked
+    /// whenever a statement fails to parse. Advances past tokens until
+    /// `current_token` has just consumed a `Token::Semicolon`, or
+    /// `peek_token` starts a new statement (or ends the enclosing block
+    /// or program), so the caller can resume parsing fresh statements.
+    /// Also stops as soon as `current_token` itself is `RBrace`/`Eof`,
+    /// so a malformed statement inside a block never swallows the
+    /// closing `}`. Always advances at least one token, guaranteeing
+    /// that a loop driven by this makes progress.
+    fn synchronize(&mut self) {
+        // Resyncing to the next statement boundary means any delimiter
+        // that was "still open" when the failed statement bailed no
+        // longer has any bearing on what comes next.
+        self.unclosed_delimiters.clear();
+        self.next_token();
+        // Track brace depth so a `;` or keyword *inside* a block that was
+        // part of the failed statement (e.g. the body of a function
+        // declaration that never got a name) doesn't look like a top-level
+        // statement boundary -- only hit depth 0 once we've consumed a
+        // `{...}` pair's worth of leftover garbage, not on its first token.
+        let mut depth: u32 = 0;
+        loop {
+            if self.current_token_is(&Token::Eof) {
+                return;
+            }
+            // current_token (not just peek_token, checked below for the
+            // token *after* whatever garbage we're still skipping) can
+            // already sit on an unambiguous statement-start keyword --
+            // most commonly right after unwinding a run of bare `;`
+            // empty statements, which each resync here one at a time.
+            // Returning immediately instead of falling through to the
+            // peek-based check keeps this from overshooting past the
+            // keyword and into whatever follows it.
+            if depth == 0
+                && matches!(
+                    self.current_token,
+                    Token::Let
+                        | Token::Mut
+                        | Token::If
+                        | Token::While
+                        | Token::For
+                        | Token::Loop
+                        | Token::Print
+                        | Token::Println
+                        | Token::Break
+                        | Token::Continue
+                )
+            {
+                return;
+            }
+            if self.current_token_is(&Token::LBrace) {
+                depth += 1;
+                self.next_token();
+                continue;
+            }
+            if self.current_token_is(&Token::RBrace) {
+                if depth == 0 {
+                    // This brace closes some *enclosing* block rather than
+                    // one we opened while skipping -- that's the real
+                    // boundary, so stop here without consuming it.
+                    return;
+                }
+                depth -= 1;
+                self.next_token();
+                continue;
+            }
+            if depth == 0 && self.current_token_is(&Token::Semicolon) {
+                return;
+            }
+            if depth == 0
+                && matches!(
+                    self.peek_token,
+                    Token::Let
+                        | Token::Mut
+                        | Token::If
+                        | Token::While
+                        | Token::For
+                        | Token::Loop
+                        | Token::Print
+                        | Token::Println
+                        | Token::Break
+                        | Token::Continue
+                        | Token::RBrace
+                        | Token::Eof
+                )
+            {
+                return;
+            }
+            self.next_token();
+        }
+    }
+
     fn parse_statement(&mut self) -> Option<Statement> {
         let stmt = match self.current_token {
             Token::Let | Token::Mut => self.parse_let_or_mut_statement(), // Updated dispatch
@@ -151,27 +473,9 @@ impl<'a> Parser<'a> {
             Token::Print | Token::Println => self.parse_print_statement(),
             Token::Break => self.parse_break_statement(),
             Token::Continue => self.parse_continue_statement(),
-            Token::Identifier(ident_name) => { // Capture ident_name directly
-                if self.peek_token_is(&Token::Assign) {
-                    // This is an Assignment statement
-                    let name = ident_name.clone(); // Clone the captured name
-                    self.next_token(); // Consume Identifier, current_token is now Assign
-                    self.next_token(); // Consume Assign, current_token is now the start of the RHS expression
-                    
-                    match self.parse_expression(Precedence::LOWEST) {
-                        Some(value_expr) => Some(Statement::Assignment { name, value_expr }),
-                        None => {
-                            self.errors.push(format!("Expected expression after '=' for assignment to '{}'", name));
-                            None
-                        }
-                    }
-                } else {
-                    // Not an assignment, so it's a regular expression statement (e.g. function call or just an expression)
-                    // parse_expression_statement expects current_token to be the start of the expression.
-                    // Since current_token is already the Identifier, this is correct.
-                    self.parse_expression_statement()
-                }
-            }
+            Token::Fn => self.parse_fn_declaration(),
+            Token::Return => self.parse_return_statement(),
+            Token::Identifier(_) => self.parse_identifier_led_statement(),
             Token::Semicolon => { // Empty statement
                 // self.next_token(); // The main loop will advance.
                 return None; 
@@ -186,19 +490,48 @@ impl<'a> Parser<'a> {
         // and are not control flow keywords like break/continue that might not need them.
         // Specifically, LetDecl, Assignment, ExprStatement, Print often have semicolons.
         match stmt {
-            Some(Statement::LetDecl{..}) | 
-            Some(Statement::Assignment{..}) | 
-            Some(Statement::ExprStatement{..}) | 
+            // A bare expression statement has no closing token of its own
+            // (unlike `let`'s value_expr or `print`'s `)`) to mark where it
+            // ends, so two of them sitting next to each other with nothing
+            // between (`a b`) is never intentional -- almost always a
+            // missing `;` or a typo -- even though either `a` or `b` alone
+            // could legally start a fresh statement. Held to the stricter
+            // `can_follow_bare_expr_statement`, unlike the other arm below.
+            Some(Statement::ExprStatement{..}) => {
+                if self.peek_token_is(&Token::Semicolon) {
+                    self.next_token();
+                } else if !can_follow_bare_expr_statement(&self.peek_token) {
+                    self.errors.push(ParseError::MissingSemicolon {
+                        position: self.current_end_pos,
+                        suggestion: ";".to_string(),
+                    });
+                }
+            }
+            Some(Statement::LetDecl{..}) |
+            Some(Statement::Assignment{..}) |
             Some(Statement::Print{..}) |
             Some(Statement::Break) | // Break and Continue can also be optionally terminated
-            Some(Statement::Continue) => {
+            Some(Statement::Continue) |
+            Some(Statement::Return{..}) => {
                 if self.peek_token_is(&Token::Semicolon) {
                     self.next_token(); // Consume the optional semicolon. current_token is now the semicolon.
+                } else if !can_start_statement(&self.peek_token) {
+                    // The next token can neither continue this statement
+                    // nor begin another one -- almost certainly a missing
+                    // `;`. Anchor the error at the end of the token we
+                    // just finished on, not at the confusing token ahead.
+                    self.errors.push(ParseError::MissingSemicolon {
+                        position: self.current_end_pos,
+                        suggestion: ";".to_string(),
+                    });
                 }
             }
-            // For block statements (If, While, For, Loop), they end with '}', no semicolon needed after the '}'.
+            // Block statements (If, While, For, Loop) end with '}' and are
+            // already unambiguous with whatever comes next -- a second
+            // block-like statement, or any other kind, can sit right after
+            // with no semicolon or newline required.
             // None is for empty semicolon statements, already handled.
-            _ => {} 
+            _ => {}
         }
         stmt
     }
@@ -209,7 +542,7 @@ impl<'a> Parser<'a> {
             Token::Mut => true,
             Token::Let => false,
             _ => { // Should not be called if current_token is not Let or Mut
-                self.errors.push(format!("parse_let_or_mut_statement called with unexpected token {:?}", self.current_token));
+                self.push_error(format!("parse_let_or_mut_statement called with unexpected token {:?}", self.current_token));
                 return None;
             }
         };
@@ -231,20 +564,7 @@ impl<'a> Parser<'a> {
         let mut type_annotation: Option<String> = None;
         if self.peek_token_is(&Token::Colon) {
             self.next_token(); // Consume ':'
-            
-            if !matches!(self.peek_token, Token::Identifier(_)) {
-                 self.peek_error(&Token::Identifier("TYPE_NAME".to_string()));
-                 // Decide if this is a fatal error for the statement or if we can proceed without type_ann
-                 // For now, let's make it fatal for the type annotation part.
-                 return None; 
-            }
-            self.next_token(); // Consume the type identifier
-            match &self.current_token {
-                 Token::Identifier(type_name_str) => {
-                    type_annotation = Some(type_name_str.clone());
-                }
-                _ => return None, // Unlikely
-            }
+            type_annotation = Some(self.parse_type_name()?);
         }
 
         // Assignment Operator
@@ -255,15 +575,12 @@ impl<'a> Parser<'a> {
         // current_token is now '='
         self.next_token(); // Consume '=', move to the start of the expression
 
-        // Value Expression
-        let value_expr = match self.parse_expression(Precedence::LOWEST) {
-            Some(expr) => expr,
-            None => {
-                self.errors.push(format!("Expected expression after '=' for variable '{}'", name));
-                return None;
-            }
-        };
-        
+        // Value Expression. `parse_expression` already records whatever error
+        // caused it to fail (an unknown prefix token, an unclosed delimiter,
+        // ...) -- pushing another "missing expression" diagnostic here would
+        // just be a second report of the same problem.
+        let value_expr = self.parse_expression(Precedence::LOWEST)?;
+
         // Optional semicolon is handled by the main parse_statement function's suffix logic.
 
         Some(Statement::LetDecl {
@@ -274,7 +591,51 @@ impl<'a> Parser<'a> {
         })
     }
 
-    // Removed parse_assignment_statement as its logic is now in parse_statement's Identifier arm.
+    /// An identifier can start a plain expression statement (`foo();`), a
+    /// simple assignment (`x = e;`), a compound assignment (`x += e;`, and
+    /// likewise for `-= *= /= %=`), or either form through an index target
+    /// (`arr[i] = e;`, `arr[i] += e;`). Parse the full left-hand expression
+    /// first and decide based on what follows it, rather than special-casing
+    /// `Identifier` vs `Identifier LBracket` up front. A compound form is
+    /// desugared here into `target = target OP rhs`, matching how rustc's
+    /// `AssocOp` models `+=` as sugar over plain assignment -- this keeps
+    /// every other pass (typeck, codegen, the interpreter) working with a
+    /// single `Statement::Assignment` shape.
+    fn parse_identifier_led_statement(&mut self) -> Option<Statement> {
+        let target_expr = self.parse_expression(Precedence::LOWEST)?;
+
+        let compound_op = match &self.peek_token {
+            Token::PlusAssign => Some(BinaryOperator::Plus),
+            Token::MinusAssign => Some(BinaryOperator::Minus),
+            Token::MultiplyAssign => Some(BinaryOperator::Multiply),
+            Token::DivideAssign => Some(BinaryOperator::Divide),
+            Token::ModuloAssign => Some(BinaryOperator::Modulo),
+            _ => None,
+        };
+
+        if compound_op.is_none() && !self.peek_token_is(&Token::Assign) {
+            return Some(Statement::ExprStatement { expr: target_expr });
+        }
+
+        if !is_assignable_target(&target_expr) {
+            self.push_error(format!("Cannot assign to '{:?}'", target_expr));
+            return None;
+        }
+
+        self.next_token(); // Consume the last token of the target expr; current is now the assignment operator.
+        self.next_token(); // Consume the assignment operator; current is the start of the RHS expression.
+
+        // See the matching comment in parse_let_statement: parse_expression
+        // has already recorded the error that made it fail.
+        let rhs = self.parse_expression(Precedence::LOWEST)?;
+
+        let value_expr = match compound_op {
+            Some(op) => Expr::BinaryOp { left: Box::new(target_expr.clone()), op, right: Box::new(rhs) },
+            None => rhs,
+        };
+
+        Some(Statement::Assignment { target: target_expr, value_expr })
+    }
 
     fn parse_expression_statement(&mut self) -> Option<Statement> {
         // current_token is the beginning of an expression
@@ -291,53 +652,88 @@ impl<'a> Parser<'a> {
 
         // self.next_token(); // Consume '{', current_token is now the first token of the block or '}'
 
+        self.enter_delimiter(Token::LBrace);
         let mut statements = Vec::new();
+        let mut result = None;
         // Current token is LBRACE. Consume it and move to the first statement or RBRACE
-        self.next_token(); 
+        self.next_token();
 
         while !self.current_token_is(&Token::RBrace) && !self.current_token_is(&Token::Eof) {
-            // parse_statement parses one statement.
-            // The optional semicolon logic is handled within parse_statement itself.
-            // The main loop in parse_program calls next_token() after parse_statement().
-            // We replicate that pattern here for statements within a block.
+            // parse_statement parses one statement. The optional semicolon
+            // logic is handled within parse_statement itself.
             match self.parse_statement() {
-                Some(statement) => statements.push(statement),
-                None => {
-                    // If parse_statement returns None (e.g., for an empty ';' or a parsing error for that statement),
-                    // we still need to advance to avoid getting stuck, unless it's already EOF or RBrace.
+                Some(statement) => {
+                    // Same signal `parse_program` uses for `Program::result`:
+                    // an un-terminated expression statement right before the
+                    // block's closing brace becomes the block's implicit
+                    // value instead of an ordinary statement.
+                    let had_semicolon = self.current_token_is(&Token::Semicolon);
+                    // Advance past the statement's last token to prepare
+                    // for the next one. If current_token is already
+                    // RBrace or Eof, the loop condition will handle it.
+                    self.next_token();
+
+                    if !had_semicolon && self.current_token_is(&Token::RBrace) {
+                        if let Statement::ExprStatement { expr } = statement {
+                            result = Some(expr);
+                            continue;
+                        }
+                    }
+                    statements.push(statement);
                 }
+                // Resync to the next statement boundary, same as
+                // parse_program's loop, rather than swallowing '}'.
+                None => self.synchronize(),
             }
-            // Crucially, advance the token to prepare for the next statement or the end of the block.
-            // This is similar to how parse_program's loop works.
-            // If current_token is already RBrace or Eof, the loop condition will handle it.
-            self.next_token();
         }
 
         if !self.current_token_is(&Token::RBrace) {
-            self.errors.push(format!("Unterminated block: expected '}}', got {:?}", self.current_token));
+            self.push_unexpected(Token::RBrace);
+            self.mark_innermost_delimiter_reported();
             return None;
         }
+        self.exit_delimiter();
         // Do NOT consume the RBrace here.
         // The current_token is now RBrace. The caller (e.g. parse_if_statement)
         // will finish, and the main parse_program loop's next_token() will consume the RBrace.
-        Some(Block { statements })
+        Some(Block { statements, result })
+    }
+
+    /// Recovery for an `if`/`else`/`else if`/`for`/`while`/`loop` body that
+    /// wasn't opened with `{`. Rather than aborting the whole statement,
+    /// records one `MissingBraces` diagnostic and treats the single
+    /// following statement as a synthetic one-statement `Block`, so the
+    /// rest of the program still parses and more errors can surface in
+    /// the same run. `header_pos` is the position reported as the "this is
+    /// the parsed ..." secondary note; `peek_token` must already be the
+    /// first token of the intended body (i.e. the caller has confirmed
+    /// it's not `{`).
+    fn error_block_no_opening_brace(&mut self, construct: &'static str, header_pos: Position) -> Option<Block> {
+        self.next_token(); // Move onto the body's first token.
+        self.errors.push(ParseError::MissingBraces {
+            construct,
+            header_position: header_pos,
+            body_position: self.current_pos,
+        });
+        let statement = self.parse_statement()?;
+        Some(Block { statements: vec![statement], result: None })
     }
 
     fn parse_if_statement(&mut self) -> Option<Statement> {
-        // current_token is If. No opening parenthesis expected.
+        let header_pos = self.current_pos; // Position of 'if' itself.
         self.next_token(); // Consume 'if', current is start of condition
 
         let condition = self.parse_expression(Precedence::LOWEST)?;
         // After parse_expression, current_token is the last token of the condition.
         // We now expect LBrace for the 'then' block.
 
-        if !self.expect_peek(Token::LBrace) { // Expects peek to be LBrace, then consumes it.
-            self.errors.push(format!("Expected '{{' after if condition, got {:?}", self.current_token));
-            return None;
-        }
-        // current_token is now LBrace
-        let then_block = self.parse_block_statement()?;
-        // after parse_block_statement, current_token is '}'
+        let then_block = if self.peek_token_is(&Token::LBrace) {
+            self.next_token(); // current_token is now LBrace
+            self.parse_block_statement()?
+        } else {
+            self.error_block_no_opening_brace("if", header_pos)?
+        };
+        // after parse_block_statement / recovery, current_token is '}' or the recovered body's last token.
 
         let mut else_if_blocks = Vec::new();
         let mut else_block = None;
@@ -345,58 +741,65 @@ impl<'a> Parser<'a> {
         // current_token is '}' from then_block. Peek for 'else'.
         while self.peek_token_is(&Token::Else) {
             self.next_token(); // Consume '}' (from then_block or previous else-if block), current is 'else'
-            
+
             if self.peek_token_is(&Token::If) { // This is 'else if'
                 self.next_token(); // consume 'else', current is 'if'
+                let else_if_header_pos = self.current_pos; // Position of this 'if'.
                 self.next_token(); // consume 'if', current is start of else-if-condition
-                
+
                 let else_if_condition = self.parse_expression(Precedence::LOWEST)?;
-                
-                if !self.expect_peek(Token::LBrace) {
-                    self.errors.push(format!("Expected '{{' after else if condition, got {:?}", self.current_token));
-                    return None;
-                }
-                let else_if_then_block = self.parse_block_statement()?;
+
+                let else_if_then_block = if self.peek_token_is(&Token::LBrace) {
+                    self.next_token();
+                    self.parse_block_statement()?
+                } else {
+                    self.error_block_no_opening_brace("else if", else_if_header_pos)?
+                };
                 else_if_blocks.push((else_if_condition, else_if_then_block));
             } else { // This is an 'else' block
-                if !self.expect_peek(Token::LBrace) { 
-                    self.errors.push(format!("Expected '{{' for else block, got {:?}", self.current_token));
-                    return None; 
-                }
-                else_block = Some(self.parse_block_statement()?); 
-                break; 
+                let else_header_pos = self.current_pos; // Position of 'else'.
+                else_block = Some(if self.peek_token_is(&Token::LBrace) {
+                    self.next_token();
+                    self.parse_block_statement()?
+                } else {
+                    self.error_block_no_opening_brace("else", else_header_pos)?
+                });
+                break;
             }
         }
         Some(Statement::If { condition, then_block, else_if_blocks, else_block })
     }
-    
+
     fn parse_loop_statement(&mut self) -> Option<Statement> {
-        if !self.expect_peek(Token::LBrace) { 
-            self.errors.push(format!("Expected '{{' after 'loop', got {:?}, peek: {:?}", self.current_token, self.peek_token));
-            return None;
-        }
-        let body_block = self.parse_block_statement()?; 
+        let header_pos = self.current_pos; // Position of 'loop' itself.
+        let body_block = if self.peek_token_is(&Token::LBrace) {
+            self.next_token();
+            self.parse_block_statement()?
+        } else {
+            self.error_block_no_opening_brace("loop", header_pos)?
+        };
         Some(Statement::Loop { body_block })
     }
 
     fn parse_while_statement(&mut self) -> Option<Statement> {
-        // current_token is While. No opening parenthesis expected.
+        let header_pos = self.current_pos; // Position of 'while' itself.
         self.next_token(); // Consume 'while', current is start of condition
 
         let condition = self.parse_expression(Precedence::LOWEST)?;
         // After parse_expression, current_token is the last token of the condition.
         // We now expect LBrace for the loop body.
 
-        if !self.expect_peek(Token::LBrace) { // Expects peek to be LBrace, then consumes it.
-            self.errors.push(format!("Expected '{{' after while condition, got {:?}", self.current_token));
-            return None;
-        }
-        // current_token is now LBrace
-        let body_block = self.parse_block_statement()?;
+        let body_block = if self.peek_token_is(&Token::LBrace) {
+            self.next_token(); // current_token is now LBrace
+            self.parse_block_statement()?
+        } else {
+            self.error_block_no_opening_brace("while", header_pos)?
+        };
         Some(Statement::While { condition, body_block })
     }
 
     fn parse_for_statement(&mut self) -> Option<Statement> {
+        let header_pos = self.current_pos; // Position of 'for' itself.
         // current_token is For. No opening parenthesis expected.
         self.next_token(); // Consume 'for', current is start of initializer or first ';'
         
@@ -418,8 +821,11 @@ impl<'a> Parser<'a> {
             if self.peek_token_is(&Token::Semicolon) { // If init was `let x = 1` (no semi), current is 1, peek is ;
                 self.next_token(); // current is now ;
             } else if !self.current_token_is(&Token::Semicolon) { // If init was `let x = 1;` current is ;, this is false. If `let x = 1` (no semi) and next is not semi.
-                 self.errors.push(format!("Expected ';' after for loop initializer, got {:?} (peek: {:?})", self.current_token, self.peek_token));
-                 return None;
+                 // Report the missing ';' and keep going rather than
+                 // abandoning the whole `for` header -- the condition and
+                 // increment clauses may carry their own, independent
+                 // missing-semicolon errors worth surfacing too.
+                 self.push_unexpected(Token::Semicolon);
             }
         }
         self.next_token(); // Consume ';' after initializer, current is start of condition or second ';'
@@ -434,46 +840,56 @@ impl<'a> Parser<'a> {
             if self.peek_token_is(&Token::Semicolon) { // If cond was `x < 1` (no semi), current is 1, peek is ;
                 self.next_token(); // current is now ;
             } else if !self.current_token_is(&Token::Semicolon) {
-                 self.errors.push(format!("Expected ';' after for loop condition, got {:?} (peek: {:?})", self.current_token, self.peek_token));
-                 return None;
+                 // Same reasoning as the initializer's check above.
+                 self.push_unexpected(Token::Semicolon);
             }
         }
         self.next_token(); // Consume ';' after condition, current is start of increment or ')'
 
-        let increment = if self.current_token_is(&Token::RParen) {
-            None 
+        // No parenthesized `for (...)` grammar exists in this language, so
+        // the only thing that can legitimately follow the second ';' with
+        // no increment expression is the body's opening brace.
+        //
+        // Parsed as a full statement (like `initializer`, not a bare
+        // `parse_expression`) since the idiomatic increment is an
+        // assignment (`i = i + 1`), which `parse_expression` can't
+        // represent -- assignment only exists as `Statement::Assignment`.
+        let increment = if self.current_token_is(&Token::LBrace) {
+            None
         } else {
-            self.parse_expression(Precedence::LOWEST)
+            self.parse_statement().map(Box::new)
         };
-        
+
         // After parsing increment, current_token is the last token of the increment expression.
         // After parsing increment, current_token is the last token of the increment expression,
         // OR it's the token that made us decide there's no increment (e.g., LBrace).
         // No closing parenthesis expected. We directly expect LBrace for the body.
-        
-        if !self.current_token_is(&Token::LBrace) {
-            // If current_token is not LBrace after parsing increment (or deciding there's no increment),
-            // it's an error. parse_expression for increment should leave us on its last token.
-            // So we need to expect_peek for LBrace.
-            if !self.expect_peek(Token::LBrace) {
-                 self.errors.push(format!("Expected '{{' for for-loop body, got {:?} (peek: {:?})", self.current_token, self.peek_token));
-                 return None;
-            }
-        }
-        // current_token is now LBrace
-        let body_block = self.parse_block_statement()?;
+
+        let body_block = if self.current_token_is(&Token::LBrace) {
+            self.parse_block_statement()?
+        } else if self.peek_token_is(&Token::LBrace) {
+            self.next_token(); // current_token is now LBrace
+            self.parse_block_statement()?
+        } else {
+            self.error_block_no_opening_brace("for", header_pos)?
+        };
         Some(Statement::For { initializer: initializer.map(Box::new), condition, increment, body_block })
     }
 
     fn parse_print_statement(&mut self) -> Option<Statement> {
         let newline = self.current_token_is(&Token::Println);
 
-        if !self.expect_peek(Token::LParen) { return None; } 
-        self.next_token(); 
+        if !self.expect_peek(Token::LParen) { return None; }
+        self.enter_delimiter(Token::LParen);
+        self.next_token();
 
-        let expr = self.parse_expression(Precedence::LOWEST)?; 
+        let expr = self.parse_expression(Precedence::LOWEST)?;
 
-        if !self.expect_peek(Token::RParen) { return None; } 
+        if !self.expect_peek(Token::RParen) {
+            self.mark_innermost_delimiter_reported();
+            return None;
+        }
+        self.exit_delimiter();
         // Semicolon is now optional, will be handled by parse_statement's suffix check.
         Some(Statement::Print { expr, newline })
     }
@@ -487,8 +903,108 @@ impl<'a> Parser<'a> {
         // Semicolon is now optional, will be handled by parse_statement's suffix check.
         Some(Statement::Continue)
     }
-    
-    // `parse_return_statement` would be here if `return` keyword was part of the language.
+
+    // Parses `return`, `return <expr>`, or `return;` into Statement::Return.
+    fn parse_return_statement(&mut self) -> Option<Statement> {
+        // current_token is 'return'. A following ';', '}' or Eof means no value.
+        if self.peek_token_is(&Token::Semicolon) || self.peek_token_is(&Token::RBrace) || self.peek_token_is(&Token::Eof) {
+            return Some(Statement::Return { expr: None });
+        }
+        self.next_token(); // Consume 'return', current is start of the expression
+        let expr = self.parse_expression(Precedence::LOWEST)?;
+        Some(Statement::Return { expr: Some(expr) })
+    }
+
+    // Parses `fn name(param1, param2: Type): ReturnType { ... }` into
+    // Statement::FnDecl, reusing parse_block_statement for the body.
+    fn parse_fn_declaration(&mut self) -> Option<Statement> {
+        // current_token is 'fn'.
+        if !matches!(self.peek_token, Token::Identifier(_)) {
+            self.peek_error(&Token::Identifier("FUNCTION_NAME".to_string()));
+            return None;
+        }
+        self.next_token(); // Consume 'fn', current is the function name
+        let name = match &self.current_token {
+            Token::Identifier(n) => n.clone(),
+            _ => return None,
+        };
+
+        if !self.expect_peek(Token::LParen) {
+            return None;
+        }
+
+        let mut params = Vec::new();
+        if self.peek_token_is(&Token::RParen) {
+            self.next_token(); // Consume '(', current is ')'
+        } else {
+            self.next_token(); // Consume '(', current is first param name
+            params.push(self.parse_fn_param()?);
+            while self.peek_token_is(&Token::Comma) {
+                self.next_token(); // current is ','
+                self.next_token(); // current is next param name
+                params.push(self.parse_fn_param()?);
+            }
+            if !self.expect_peek(Token::RParen) {
+                return None;
+            }
+        }
+        // current_token is now ')'
+
+        let mut return_type = None;
+        if self.peek_token_is(&Token::Colon) {
+            self.next_token(); // Consume ')', current is ':'
+            return_type = Some(self.parse_type_name()?);
+        }
+
+        if !self.expect_peek(Token::LBrace) {
+            self.push_unexpected(Token::LBrace);
+            return None;
+        }
+        let body = self.parse_block_statement()?;
+
+        Some(Statement::FnDecl { name, params, return_type, body })
+    }
+
+    // Parses a single `name` or `name: Type` entry in a function's parameter list.
+    // Expects current_token to already be the parameter name identifier.
+    fn parse_fn_param(&mut self) -> Option<(String, String)> {
+        let name = match &self.current_token {
+            Token::Identifier(n) => n.clone(),
+            _ => {
+                self.push_unexpected(Token::Identifier("PARAM_NAME".to_string()));
+                return None;
+            }
+        };
+        let mut ty = "int".to_string(); // Default when no annotation is given.
+        if self.peek_token_is(&Token::Colon) {
+            self.next_token(); // Consume name, current is ':'
+            ty = self.parse_type_name()?;
+        }
+        Some((name, ty))
+    }
+
+    // Parses the base type identifier following a ':', plus any trailing `[]`
+    // pairs for array types (e.g. `int`, `int[]`, `int[][]`).
+    // Expects current_token to be the ':' and peek_token to be the type identifier.
+    fn parse_type_name(&mut self) -> Option<String> {
+        if !matches!(self.peek_token, Token::Identifier(_)) {
+            self.peek_error(&Token::Identifier("TYPE_NAME".to_string()));
+            return None;
+        }
+        self.next_token(); // Consume ':', current_token is now the type identifier
+        let mut type_name = match &self.current_token {
+            Token::Identifier(n) => n.clone(),
+            _ => return None,
+        };
+        while self.peek_token_is(&Token::LBracket) {
+            self.next_token(); // current_token is now '['
+            if !self.expect_peek(Token::RBracket) { // Consumes ']', current_token is now ']'
+                return None;
+            }
+            type_name.push_str("[]");
+        }
+        Some(type_name)
+    }
 
     // === Expression Parsing (Pratt Parser) ===
 
@@ -496,15 +1012,24 @@ impl<'a> Parser<'a> {
         // Prefix part
         let mut left_expr_opt = match self.current_token {
             Token::Identifier(_) => self.parse_identifier(),
-            Token::Integer(_) => self.parse_integer_literal(),
+            Token::Integer { .. } => self.parse_integer_literal(),
             Token::Float(_) => self.parse_float_literal(),
             Token::String(_) => self.parse_string_literal(),
             Token::True | Token::False => self.parse_boolean_literal(),
             Token::Bang | Token::Minus => self.parse_prefix_expression(), // Note: Minus is also infix
             Token::LParen => self.parse_grouped_expression(),
+            Token::LBracket => self.parse_array_literal(),
+            Token::LBrace => self.parse_map_literal(),
+            Token::Backslash => self.parse_operator_fn_expression(),
             ref tok if is_prefix_operator(tok) => self.parse_prefix_expression(), // General prefix
             _ => {
-                self.errors.push(format!("No prefix parse function for {:?} found. Peek: {:?}", self.current_token, self.peek_token));
+                // "No prefix parse function for this token" is the sole
+                // source of a `None` from this function, so every caller
+                // that propagates it (via `?` or an explicit match) can
+                // trust this diagnostic already covers "expected an
+                // expression" -- suppress it too while an enclosing
+                // delimiter is still unclosed.
+                self.push_secondary_error(ParseError::UnknownPrefixOperator(self.current_token.clone(), self.current_pos));
                 return None;
             }
         };
@@ -514,12 +1039,12 @@ impl<'a> Parser<'a> {
         // We need to look at peek_token for the infix operator.
         while !self.peek_token_is(&Token::Semicolon) && precedence < self.peek_precedence() {
             let peeked_token = self.peek_token.clone();
-            if !is_infix_operator(&peeked_token) && peeked_token != Token::LParen /* for call */ {
+            if !is_infix_operator(&peeked_token) && peeked_token != Token::LParen /* for call */ && peeked_token != Token::LBracket /* for index */ && peeked_token != Token::Dot /* for member access */ {
                 return left_expr_opt;
             }
 
-            self.next_token(); // Consume the prefix expression's last token, current_token is now the infix operator or '(' for call
-            
+            self.next_token(); // Consume the prefix expression's last token, current_token is now the infix operator, '(' for call, '[' for index, or '.' for member access
+
             left_expr_opt = match self.current_token {
                 // Binary operators
                 Token::Plus | Token::Minus | Token::Multiply | Token::Divide | Token::Modulo |
@@ -530,9 +1055,15 @@ impl<'a> Parser<'a> {
                 Token::LParen => { // Call expression like identifier(args)
                     self.parse_call_expression(left_expr_opt?)
                 }
+                Token::LBracket => { // Index expression like arr[i]
+                    self.parse_index_expression(left_expr_opt?)
+                }
+                Token::Dot => { // Member access like obj.field, also the callee of obj.method(args)
+                    self.parse_member_expression(left_expr_opt?)
+                }
                 _ => {
                     // This should not be reached if is_infix_operator and precedence checks are correct
-                    return left_expr_opt; 
+                    return left_expr_opt;
                 }
             };
         }
@@ -548,9 +1079,10 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_integer_literal(&mut self) -> Option<Expr> {
-        // current_token is Integer
+        // current_token is Integer; the radix only matters for round-tripping
+        // the original spelling, so the AST keeps the plain i64 value.
         match self.current_token {
-            Token::Integer(val) => Some(Expr::Integer(val)),
+            Token::Integer { value, .. } => Some(Expr::Integer(value)),
             _ => None,
         }
     }
@@ -562,13 +1094,13 @@ impl<'a> Parser<'a> {
                 match s_val.parse::<f64>() {
                     Ok(f_val) => Some(Expr::Float(f_val)),
                     Err(_) => {
-                        self.errors.push(format!("Could not parse float string '{}'", s_val));
+                        self.errors.push(ParseError::InvalidFloatLiteral(s_val.clone(), self.current_pos));
                         None
                     }
                 }
             }
             _ => { // Should not happen if called on Token::Float
-                self.errors.push(format!("Expected float literal, got {:?}", self.current_token));
+                self.push_error(format!("Expected float literal, got {:?}", self.current_token));
                 None
             }
         }
@@ -598,7 +1130,7 @@ impl<'a> Parser<'a> {
             Token::Bang => UnaryOperator::Not,
             Token::Minus => UnaryOperator::Negate,
             _ => {
-                self.errors.push(format!("Unknown prefix operator: {:?}", operator_token));
+                self.errors.push(ParseError::UnknownPrefixOperator(operator_token.clone(), self.current_pos));
                 return None;
             }
         };
@@ -608,25 +1140,29 @@ impl<'a> Parser<'a> {
         Some(Expr::UnaryOp { op, expr: Box::new(expr) })
     }
 
+    // Parses the backslash operator-fn prefix form (e.g. \+), boxing a
+    // binary operator as a value: \+ is equivalent to fn(a, b) { a + b }.
+    fn parse_operator_fn_expression(&mut self) -> Option<Expr> {
+        // current_token is Backslash
+        self.next_token(); // Consume '\', current_token is now the operator
+        let operator_token = self.current_token.clone();
+        let op = match token_to_binary_operator(&operator_token) {
+            Some(op) if is_operator_fn_operator(&op) => op,
+            _ => {
+                self.errors.push(ParseError::UnknownPrefixOperator(operator_token.clone(), self.current_pos));
+                return None;
+            }
+        };
+        Some(Expr::OperatorFn(op))
+    }
+
     fn parse_infix_expression(&mut self, left: Expr) -> Option<Expr> {
         // current_token is the infix operator (e.g. +, ==)
         let operator_token = self.current_token.clone();
-        let op = match operator_token {
-            Token::Plus => BinaryOperator::Plus,
-            Token::Minus => BinaryOperator::Minus,
-            Token::Multiply => BinaryOperator::Multiply,
-            Token::Divide => BinaryOperator::Divide,
-            Token::Modulo => BinaryOperator::Modulo,
-            Token::Eq => BinaryOperator::Eq,
-            Token::NotEq => BinaryOperator::NotEq,
-            Token::Lt => BinaryOperator::Lt,
-            Token::Lte => BinaryOperator::Lte,
-            Token::Gt => BinaryOperator::Gt,
-            Token::Gte => BinaryOperator::Gte,
-            Token::And => BinaryOperator::And,
-            Token::Or => BinaryOperator::Or,
-            _ => {
-                self.errors.push(format!("Unknown infix operator: {:?}", operator_token));
+        let op = match token_to_binary_operator(&operator_token) {
+            Some(op) => op,
+            None => {
+                self.errors.push(ParseError::UnknownInfixOperator(operator_token.clone(), self.current_pos));
                 return None;
             }
         };
@@ -639,27 +1175,30 @@ impl<'a> Parser<'a> {
     
     fn parse_grouped_expression(&mut self) -> Option<Expr> {
         // current_token is LParen
+        self.enter_delimiter(Token::LParen);
         self.next_token(); // Consume '(', current_token is start of inner expression
         let expr = self.parse_expression(Precedence::LOWEST);
         // After parse_expression, current_token is last token of inner expression.
         // Expect peek_token to be RParen.
         if !self.expect_peek(Token::RParen) { // Consumes RParen, current_token is now RParen
-            return None; 
+            self.mark_innermost_delimiter_reported();
+            return None;
         }
         // current_token is now RParen.
+        self.exit_delimiter();
         expr
     }
 
-    fn parse_call_expression(&mut self, function_identifier_expr: Expr) -> Option<Expr> {
-        // `function_identifier_expr` is the expression for the function name (e.g. Identifier("add"))
+    // Infix handler for `callee(args...)`, triggered from parse_expression's
+    // infix loop when peek_token is LParen. Parses a comma-separated
+    // argument list into Expr::Call. `callee_expr` can be any expression --
+    // an identifier (`add(1, 2)`), a member access (`obj.method(arg)`), or
+    // even another call (`get_fn()()`) -- though only an Expr::Identifier
+    // callee is actually invocable at runtime today.
+    fn parse_call_expression(&mut self, callee_expr: Expr) -> Option<Expr> {
         // `current_token` is LParen, consumed from parse_expression's infix loop.
-        let callee = match function_identifier_expr {
-            Expr::Identifier(name) => name,
-            _ => {
-                self.errors.push(format!("Expected function name (identifier) for call, got {:?}", function_identifier_expr));
-                return None;
-            }
-        };
+        self.enter_delimiter(Token::LParen);
+        let callee = Box::new(callee_expr);
 
         let mut args = Vec::new();
         if self.peek_token_is(&Token::RParen) { // No arguments: add()
@@ -678,12 +1217,124 @@ impl<'a> Parser<'a> {
             }
             // Expect ')'
             if !self.expect_peek(Token::RParen) { // Consumes ')', current_token is now ')'
-                return None; 
+                self.mark_innermost_delimiter_reported();
+                return None;
             }
         }
         // current_token is now RParen.
+        self.exit_delimiter();
         Some(Expr::Call { callee, args })
     }
+
+    /// Prefix handler for `[`, registered on `Token::LBracket` in
+    /// `parse_expression`'s prefix dispatch, covering the comma-separated
+    /// literal form (including the empty `[]` edge case below) that feeds
+    /// `Expr::ArrayLiteral`. Paired with `parse_index_expression` as the
+    /// infix handler for the same token, this is already the full
+    /// array-literal-and-indexing story this chunk asks for.
+    fn parse_array_literal(&mut self) -> Option<Expr> {
+        // current_token is LBracket
+        self.enter_delimiter(Token::LBracket);
+        let mut elements = Vec::new();
+        if self.peek_token_is(&Token::RBracket) {
+            self.next_token(); // Consume '[', current_token is now ']'
+        } else {
+            self.next_token(); // Consume '[', current_token is start of first element
+            elements.push(self.parse_expression(Precedence::LOWEST)?);
+
+            while self.peek_token_is(&Token::Comma) {
+                self.next_token(); // current is ','
+                self.next_token(); // current is start of next element
+                elements.push(self.parse_expression(Precedence::LOWEST)?);
+            }
+            if !self.expect_peek(Token::RBracket) { // Consumes ']', current_token is now ']'
+                self.mark_innermost_delimiter_reported();
+                return None;
+            }
+        }
+        // current_token is now RBracket.
+        self.exit_delimiter();
+        Some(Expr::ArrayLiteral(elements))
+    }
+
+    fn parse_index_expression(&mut self, target_expr: Expr) -> Option<Expr> {
+        // `target_expr` is the expression being indexed (e.g. Identifier("arr")).
+        // `current_token` is LBracket, consumed from parse_expression's infix loop.
+        self.enter_delimiter(Token::LBracket);
+        self.next_token(); // Consume '[', current_token is start of the index expression
+        let index = self.parse_expression(Precedence::LOWEST)?;
+        if !self.expect_peek(Token::RBracket) { // Consumes ']', current_token is now ']'
+            self.mark_innermost_delimiter_reported();
+            return None;
+        }
+        // current_token is now RBracket.
+        self.exit_delimiter();
+        Some(Expr::Index { target: Box::new(target_expr), index: Box::new(index) })
+    }
+
+    // Infix handler for `target.field`, triggered from parse_expression's
+    // infix loop when peek_token is Dot. Produces Expr::Member, which is
+    // also the callee shape parse_call_expression expects for method calls.
+    fn parse_member_expression(&mut self, target_expr: Expr) -> Option<Expr> {
+        // `current_token` is Dot, consumed from parse_expression's infix loop.
+        if !matches!(self.peek_token, Token::Identifier(_)) {
+            self.peek_error(&Token::Identifier("FIELD_NAME".to_string()));
+            return None;
+        }
+        self.next_token(); // Consume '.', current_token is now the field identifier
+        let field = match &self.current_token {
+            Token::Identifier(name) => name.clone(),
+            _ => unreachable!("checked above that peek_token is Token::Identifier"),
+        };
+        Some(Expr::Member { target: Box::new(target_expr), field })
+    }
+
+    /// Prefix handler for `{`, registered on `Token::LBrace` in
+    /// `parse_expression`'s prefix dispatch. Parses `{ key: expr, ... }`
+    /// into `Expr::Map`, using the same comma-loop shape (and empty-literal
+    /// edge case) as `parse_array_literal`.
+    fn parse_map_literal(&mut self) -> Option<Expr> {
+        // current_token is LBrace
+        self.enter_delimiter(Token::LBrace);
+        let mut entries = Vec::new();
+        if self.peek_token_is(&Token::RBrace) {
+            self.next_token(); // Consume '{', current_token is now '}'
+        } else {
+            self.next_token(); // Consume '{', current_token is start of first key
+            entries.push(self.parse_map_entry()?);
+
+            while self.peek_token_is(&Token::Comma) {
+                self.next_token(); // current is ','
+                self.next_token(); // current is start of next key
+                entries.push(self.parse_map_entry()?);
+            }
+            if !self.expect_peek(Token::RBrace) { // Consumes '}', current_token is now '}'
+                self.mark_innermost_delimiter_reported();
+                return None;
+            }
+        }
+        // current_token is now RBrace.
+        self.exit_delimiter();
+        Some(Expr::Map(entries))
+    }
+
+    fn parse_map_entry(&mut self) -> Option<(String, Expr)> {
+        // current_token is the key identifier
+        let key = match &self.current_token {
+            Token::Identifier(name) => name.clone(),
+            _ => {
+                self.push_unexpected(Token::Identifier("KEY_NAME".to_string()));
+                return None;
+            }
+        };
+        if !self.expect_peek(Token::Colon) { // Consumes ':', current_token is now ':'
+            return None;
+        }
+        self.next_token(); // Consume ':', current_token is start of the value expression
+        let value = self.parse_expression(Precedence::LOWEST)?;
+        // current_token is now the last token of the value expression.
+        Some((key, value))
+    }
 }
 
 // Helper function to identify tokens that can start a prefix expression
@@ -691,6 +1342,40 @@ fn is_prefix_operator(token: &Token) -> bool {
     matches!(token, Token::Bang | Token::Minus)
 }
 
+/// Whether `token` could legally begin a new statement or terminate the
+/// current one -- i.e. every token `parse_statement`'s dispatch (directly,
+/// or via `parse_expression`'s prefix dispatch) would accept without first
+/// emitting its own "unexpected token" error. Used only to decide whether
+/// a missing `;` is the likely cause when a statement ends and the next
+/// token doesn't continue it (see `ParseError::MissingSemicolon`).
+fn can_start_statement(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::Let | Token::Mut | Token::If | Token::Loop | Token::While | Token::For |
+        Token::Print | Token::Println | Token::Break | Token::Continue | Token::Fn | Token::Return |
+        Token::Semicolon | Token::Eof | Token::RBrace |
+        Token::Identifier(_) | Token::Integer { .. } | Token::Float(_) | Token::String(_) |
+        Token::True | Token::False | Token::LParen | Token::LBracket | Token::LBrace | Token::Backslash
+    ) || is_prefix_operator(token)
+}
+
+/// Like `can_start_statement`, but for what may directly follow a bare
+/// `Statement::ExprStatement` with no separating `;`: only a keyword-led
+/// statement or the end of the enclosing block/program qualifies. Every
+/// token that could itself start *another* bare expression statement
+/// (an identifier, a literal, `(`, `[`, `{`, `\`, a prefix operator) is
+/// excluded, since two such expressions sitting next to each other
+/// (`a b`) is never intentional -- unlike e.g. `let x = 1 print(x)`,
+/// where `print` unambiguously begins something new.
+fn can_follow_bare_expr_statement(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::Let | Token::Mut | Token::If | Token::Loop | Token::While | Token::For |
+        Token::Print | Token::Println | Token::Break | Token::Continue | Token::Fn | Token::Return |
+        Token::Semicolon | Token::Eof | Token::RBrace
+    )
+}
+
 // Helper function to identify tokens that can be infix operators
 fn is_infix_operator(token: &Token) -> bool {
     matches!(token, Token::Plus | Token::Minus | Token::Multiply | Token::Divide | Token::Modulo |
@@ -698,6 +1383,37 @@ fn is_infix_operator(token: &Token) -> bool {
                     Token::And | Token::Or)
 }
 
+// The token -> BinaryOperator mapping shared by parse_infix_expression and
+// the backslash operator-fn prefix handler below.
+fn token_to_binary_operator(token: &Token) -> Option<BinaryOperator> {
+    match token {
+        Token::Plus => Some(BinaryOperator::Plus),
+        Token::Minus => Some(BinaryOperator::Minus),
+        Token::Multiply => Some(BinaryOperator::Multiply),
+        Token::Divide => Some(BinaryOperator::Divide),
+        Token::Modulo => Some(BinaryOperator::Modulo),
+        Token::Eq => Some(BinaryOperator::Eq),
+        Token::NotEq => Some(BinaryOperator::NotEq),
+        Token::Lt => Some(BinaryOperator::Lt),
+        Token::Lte => Some(BinaryOperator::Lte),
+        Token::Gt => Some(BinaryOperator::Gt),
+        Token::Gte => Some(BinaryOperator::Gte),
+        Token::And => Some(BinaryOperator::And),
+        Token::Or => Some(BinaryOperator::Or),
+        _ => None,
+    }
+}
+
+// Operators eligible for the `\op` boxed-operator prefix form. Deliberately
+// narrower than token_to_binary_operator's full set: And/Or are boolean
+// short-circuit forms, not plain two-argument functions, so they're excluded.
+fn is_operator_fn_operator(op: &BinaryOperator) -> bool {
+    matches!(op, BinaryOperator::Plus | BinaryOperator::Minus | BinaryOperator::Multiply |
+                 BinaryOperator::Divide | BinaryOperator::Modulo | BinaryOperator::Eq |
+                 BinaryOperator::NotEq | BinaryOperator::Lt | BinaryOperator::Lte |
+                 BinaryOperator::Gt | BinaryOperator::Gte)
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -714,7 +1430,7 @@ mod tests {
             assert!(program_result.is_err(), "Expected parsing errors, but got Ok for input: '{}'", input);
             let errors = program_result.unwrap_err();
             assert_eq!(errors.len(), expected_errors, "Wrong number of parsing errors for input: '{}'. Got: {:?}, Expected: {}", input, errors, expected_errors);
-            return Program { statements: vec![] }; // Dummy program for error cases
+            return Program { statements: vec![], result: None, statement_spans: vec![] }; // Dummy program for error cases
         } else {
             assert!(program_result.is_ok(), "Expected successful parse for input: '{}', but got errors: {:?}", input, program_result.unwrap_err());
             let program = program_result.unwrap();
@@ -724,10 +1440,29 @@ mod tests {
         }
     }
 
+    // Like `run_parser_test`, but for inputs with no parse errors that are
+    // then run through `optimize::optimize` at the given level -- lets a
+    // test assert the *optimized* statement count directly, rather than
+    // parsing the program itself and calling `optimize` by hand.
+    fn run_parser_test_optimized(input: &str, level: crate::optimize::OptimizationLevel, expected_stmts: usize) -> Program {
+        let l = Lexer::new(input);
+        let mut p = Parser::new(l);
+        let program = p.parse_program().unwrap_or_else(|errors| {
+            panic!("Expected successful parse for input: '{}', but got errors: {:?}", input, errors)
+        });
+        let program = crate::optimize::optimize(program, level);
+        assert_eq!(
+            program.statements.len(), expected_stmts,
+            "Wrong number of optimized statements for input: '{}'. Got: {}, Expected: {}",
+            input, program.statements.len(), expected_stmts
+        );
+        program
+    }
+
     #[test]
     fn test_let_statements() {
         let tests = vec![
-            ("let x = 5;", "x", None, false, Expr::Integer(5)),
+            ("let x = 5;", "x", None::<&str>, false, Expr::Integer(5)),
             ("let y = 10.5", "y", None, false, Expr::Float(10.5)),
             ("let z = true", "z", None, false, Expr::Boolean(true)),
             ("let s = \"hello\";", "s", None, false, Expr::StringLiteral("hello".to_string())),
@@ -820,8 +1555,8 @@ mod tests {
         for (input, expected_name, expected_expr) in tests {
             let program = run_parser_test(input, 1, 0);
             match &program.statements[0] {
-                Statement::Assignment { name, value_expr } => {
-                    assert_eq!(name, expected_name);
+                Statement::Assignment { target, value_expr } => {
+                    assert_eq!(*target, Expr::Identifier(expected_name.to_string()));
                     assert_eq!(*value_expr, expected_expr);
                 }
                 _ => panic!("Expected Assignment statement for input: {}", input),
@@ -857,7 +1592,11 @@ mod tests {
         run_parser_test("\"test_string\"", 1, 0);
         let program = run_parser_test("3.14", 1, 0); // Test float literal expression
         assert_eq!(program.statements[0], Statement::ExprStatement { expr: Expr::Float(3.14_f64) });
-        let full_input = "3.14\nfalse";
+        // Two bare expression statements still need an explicit `;`
+        // between them (see test_adjacent_bare_expression_statements_require_semicolon) --
+        // unlike a keyword-led statement, neither side has a token marking
+        // where it ends.
+        let full_input = "3.14;\nfalse";
         run_parser_test(full_input, 2, 0);
     }
     
@@ -888,6 +1627,38 @@ mod tests {
         run_parser_test("3 * 8", 1, 0);
     }
     
+    #[test]
+    fn test_operator_fn_expressions_all_allowed_operators() {
+        let tests = vec![
+            ("\\+", BinaryOperator::Plus),
+            ("\\-", BinaryOperator::Minus),
+            ("\\*", BinaryOperator::Multiply),
+            ("\\/", BinaryOperator::Divide),
+            ("\\%", BinaryOperator::Modulo),
+            ("\\==", BinaryOperator::Eq),
+            ("\\!=", BinaryOperator::NotEq),
+            ("\\<", BinaryOperator::Lt),
+            ("\\<=", BinaryOperator::Lte),
+            ("\\>", BinaryOperator::Gt),
+            ("\\>=", BinaryOperator::Gte),
+        ];
+        for (input, expected_op) in tests {
+            let program = run_parser_test(input, 1, 0);
+            match &program.statements[0] {
+                Statement::ExprStatement { expr: Expr::OperatorFn(op) } => {
+                    assert_eq!(*op, expected_op, "Wrong operator for input: '{}'", input);
+                }
+                _ => panic!("Not an OperatorFn expression for input: '{}'. Got {:?}", input, program.statements[0]),
+            }
+        }
+    }
+
+    #[test]
+    fn test_operator_fn_rejects_non_arithmetic_operators() {
+        run_parser_test("\\&&", 0, 1);
+        run_parser_test("\\||", 0, 1);
+    }
+
     #[test]
     fn test_operator_precedence_parsing() {
         // Semicolons are optional, so we can test the raw expressions.
@@ -912,9 +1683,9 @@ mod tests {
         let program = run_parser_test("myFunction(arg1, 2.5, arg3 + 4)", 1, 0);
          match &program.statements[0] {
             Statement::ExprStatement{ expr: Expr::Call{callee, args} } => {
-                assert_eq!(callee, "myFunction");
+                assert_eq!(**callee, Expr::Identifier("myFunction".to_string()));
                 assert_eq!(args.len(), 3);
-                assert!(matches!(args[0], Expr::Identifier(id) if id == "arg1"));
+                assert!(matches!(&args[0], Expr::Identifier(id) if id == "arg1"));
                 assert_eq!(args[1], Expr::Float(2.5_f64)); // Check float arg
                 assert!(matches!(args[2], Expr::BinaryOp{op: BinaryOperator::Plus, ..}));
             }
@@ -947,7 +1718,7 @@ mod tests {
         let expr_result = parser.parse_float_literal();
         assert!(expr_result.is_none());
         assert_eq!(parser.errors.len(), 1);
-        assert!(parser.errors[0].contains("Could not parse float string 'not-a-float'"));
+        assert!(matches!(&parser.errors[0], ParseError::InvalidFloatLiteral(s, _) if s == "not-a-float"));
     }
 
     #[test]
@@ -1106,6 +1877,370 @@ mod tests {
         run_parser_test(input, 1, 0);
     }
 
+    #[test]
+    fn test_block_statements_need_no_separator() {
+        // Block-like statements (if/loop/while/for) end with '}', which is
+        // already unambiguous with whatever follows -- no semicolon or
+        // newline should be required between them and the next statement.
+        run_parser_test("if true {} let x = 1", 2, 0);
+        run_parser_test("loop {} loop {}", 2, 0);
+    }
+
+    #[test]
+    fn test_adjacent_bare_expression_statements_require_semicolon() {
+        // Neither `a` nor `b` has a closing token marking where it ends, so
+        // sitting them next to each other is ambiguous and should error
+        // rather than silently parse as two statements.
+        let input = "a b";
+        run_parser_test(input, 0, 1);
+    }
+
+    #[test]
+    fn test_fn_declaration_parsing() {
+        let program = run_parser_test("fn add(a: int, b: int): int { return a + b; }", 1, 0);
+        match &program.statements[0] {
+            Statement::FnDecl { name, params, return_type, body } => {
+                assert_eq!(name, "add");
+                assert_eq!(params, &vec![("a".to_string(), "int".to_string()), ("b".to_string(), "int".to_string())]);
+                assert_eq!(return_type, &Some("int".to_string()));
+                assert_eq!(body.statements.len(), 1);
+            }
+            _ => panic!("Expected FnDecl, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_fn_declaration_no_params_no_return_type() {
+        let program = run_parser_test("fn greet() { print(\"hi\"); }", 1, 0);
+        match &program.statements[0] {
+            Statement::FnDecl { name, params, return_type, .. } => {
+                assert_eq!(name, "greet");
+                assert!(params.is_empty());
+                assert_eq!(return_type, &None);
+            }
+            _ => panic!("Expected FnDecl, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_fn_param_defaults_to_int_without_annotation() {
+        let program = run_parser_test("fn double(x) { return x * 2; }", 1, 0);
+        match &program.statements[0] {
+            Statement::FnDecl { params, .. } => {
+                assert_eq!(params, &vec![("x".to_string(), "int".to_string())]);
+            }
+            _ => panic!("Expected FnDecl, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_return_statement_with_and_without_value() {
+        let program = run_parser_test("fn f() { return 5; }", 1, 0);
+        match &program.statements[0] {
+            Statement::FnDecl { body, .. } => {
+                assert_eq!(body.statements[0], Statement::Return { expr: Some(Expr::Integer(5)) });
+            }
+            _ => panic!("Expected FnDecl, got {:?}", program.statements[0]),
+        }
+
+        let program = run_parser_test("fn f() { return; }", 1, 0);
+        match &program.statements[0] {
+            Statement::FnDecl { body, .. } => {
+                assert_eq!(body.statements[0], Statement::Return { expr: None });
+            }
+            _ => panic!("Expected FnDecl, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_return_optional_semicolon() {
+        run_parser_test("fn f() { return 1 }", 1, 0);
+        run_parser_test("fn f() { return }", 1, 0);
+    }
+
+    #[test]
+    fn test_fn_body_implicit_return_becomes_block_result() {
+        let program = run_parser_test("fn add(a: int, b: int): int { a + b }", 1, 0);
+        match &program.statements[0] {
+            Statement::FnDecl { body, .. } => {
+                assert!(body.statements.is_empty());
+                assert_eq!(
+                    body.result,
+                    Some(Expr::BinaryOp {
+                        left: Box::new(Expr::Identifier("a".to_string())),
+                        op: BinaryOperator::Plus,
+                        right: Box::new(Expr::Identifier("b".to_string())),
+                    })
+                );
+            }
+            _ => panic!("Expected FnDecl, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_fn_body_trailing_semicolon_suppresses_implicit_return() {
+        let program = run_parser_test("fn add(a: int, b: int): int { a + b; }", 1, 0);
+        match &program.statements[0] {
+            Statement::FnDecl { body, .. } => {
+                assert_eq!(body.statements.len(), 1);
+                assert_eq!(body.result, None);
+            }
+            _ => panic!("Expected FnDecl, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_nested_call_expression_parsing() {
+        let program = run_parser_test("quadruple(double(x));", 1, 0);
+        match &program.statements[0] {
+            Statement::ExprStatement {
+                expr: Expr::Call { callee, args },
+            } => {
+                assert_eq!(**callee, Expr::Identifier("quadruple".to_string()));
+                assert_eq!(args.len(), 1);
+                assert_eq!(
+                    args[0],
+                    Expr::Call {
+                        callee: Box::new(Expr::Identifier("double".to_string())),
+                        args: vec![Expr::Identifier("x".to_string())],
+                    }
+                );
+            }
+            _ => panic!("Expected nested Call expression, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_fn_declaration_missing_name_errors() {
+        run_parser_test("fn (a) { return a; }", 0, 1);
+    }
+
+    #[test]
+    fn test_array_literal_parsing() {
+        let program = run_parser_test("[1, 2, 3]", 1, 0);
+        match &program.statements[0] {
+            Statement::ExprStatement { expr: Expr::ArrayLiteral(elements) } => {
+                assert_eq!(elements, &vec![Expr::Integer(1), Expr::Integer(2), Expr::Integer(3)]);
+            }
+            _ => panic!("Expected array literal, got {:?}", program.statements[0]),
+        }
+        run_parser_test("[]", 1, 0);
+    }
+
+    #[test]
+    fn test_array_type_annotation_parsing() {
+        let program = run_parser_test("let xs: int[] = [];", 1, 0);
+        match &program.statements[0] {
+            Statement::LetDecl { type_ann, .. } => {
+                assert_eq!(type_ann, &Some("int[]".to_string()));
+            }
+            _ => panic!("Expected LetDecl, got {:?}", program.statements[0]),
+        }
+        run_parser_test("let xss: int[][] = [];", 1, 0);
+    }
+
+    #[test]
+    fn test_index_expression_parsing() {
+        let program = run_parser_test("arr[0];", 1, 0);
+        match &program.statements[0] {
+            Statement::ExprStatement { expr: Expr::Index { target, index } } => {
+                assert!(matches!(**target, Expr::Identifier(ref id) if id == "arr"));
+                assert_eq!(**index, Expr::Integer(0));
+            }
+            _ => panic!("Expected index expression, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_array_literal_mixed_elements_parsing() {
+        let program = run_parser_test("[1, 2.5, true, \"s\"]", 1, 0);
+        match &program.statements[0] {
+            Statement::ExprStatement { expr: Expr::ArrayLiteral(elements) } => {
+                assert_eq!(
+                    elements,
+                    &vec![Expr::Integer(1), Expr::Float(2.5), Expr::Boolean(true), Expr::StringLiteral("s".to_string())]
+                );
+            }
+            _ => panic!("Expected array literal, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_nested_index_expression_parsing() {
+        let program = run_parser_test("matrix[i][j];", 1, 0);
+        match &program.statements[0] {
+            Statement::ExprStatement { expr: Expr::Index { target, index } } => {
+                assert!(matches!(**index, Expr::Identifier(ref id) if id == "j"));
+                match target.as_ref() {
+                    Expr::Index { target: inner_target, index: inner_index } => {
+                        assert!(matches!(**inner_target, Expr::Identifier(ref id) if id == "matrix"));
+                        assert!(matches!(**inner_index, Expr::Identifier(ref id) if id == "i"));
+                    }
+                    _ => panic!("Expected nested Index expression, got {:?}", target),
+                }
+            }
+            _ => panic!("Expected index expression, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_map_literal_parsing() {
+        let program = run_parser_test("{ x: 1, y: 2 }", 1, 0);
+        match &program.statements[0] {
+            Statement::ExprStatement { expr: Expr::Map(entries) } => {
+                assert_eq!(
+                    entries,
+                    &vec![("x".to_string(), Expr::Integer(1)), ("y".to_string(), Expr::Integer(2))]
+                );
+            }
+            _ => panic!("Expected map literal, got {:?}", program.statements[0]),
+        }
+        run_parser_test("{}", 1, 0);
+    }
+
+    #[test]
+    fn test_map_literal_index_parsing() {
+        let program = run_parser_test("m[\"key\"];", 1, 0);
+        match &program.statements[0] {
+            Statement::ExprStatement { expr: Expr::Index { target, index } } => {
+                assert!(matches!(**target, Expr::Identifier(ref id) if id == "m"));
+                assert_eq!(**index, Expr::StringLiteral("key".to_string()));
+            }
+            _ => panic!("Expected index expression, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_index_assignment_parsing() {
+        let program = run_parser_test("arr[0] = 5;", 1, 0);
+        match &program.statements[0] {
+            Statement::Assignment { target, value_expr } => {
+                match target {
+                    Expr::Index { target, index } => {
+                        assert!(matches!(**target, Expr::Identifier(ref id) if id == "arr"));
+                        assert_eq!(**index, Expr::Integer(0));
+                    }
+                    _ => panic!("Expected Index target, got {:?}", target),
+                }
+                assert_eq!(value_expr, &Expr::Integer(5));
+            }
+            _ => panic!("Expected assignment, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_member_expression_parsing() {
+        let program = run_parser_test("obj.field;", 1, 0);
+        match &program.statements[0] {
+            Statement::ExprStatement { expr: Expr::Member { target, field } } => {
+                assert!(matches!(**target, Expr::Identifier(ref id) if id == "obj"));
+                assert_eq!(field, "field");
+            }
+            _ => panic!("Expected member expression, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_method_call_chaining_parsing() {
+        let program = run_parser_test("obj.method(1, 2);", 1, 0);
+        match &program.statements[0] {
+            Statement::ExprStatement { expr: Expr::Call { callee, args } } => {
+                match callee.as_ref() {
+                    Expr::Member { target, field } => {
+                        assert!(matches!(**target, Expr::Identifier(ref id) if id == "obj"));
+                        assert_eq!(field, "method");
+                    }
+                    _ => panic!("Expected Member callee, got {:?}", callee),
+                }
+                assert_eq!(args, &vec![Expr::Integer(1), Expr::Integer(2)]);
+            }
+            _ => panic!("Expected call expression, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_chained_dot_calls_parsing() {
+        // a.b().c() -- each call's callee is itself a Member whose target is
+        // the previous call.
+        let program = run_parser_test("a.b().c();", 1, 0);
+        match &program.statements[0] {
+            Statement::ExprStatement { expr: Expr::Call { callee: outer_callee, args: outer_args } } => {
+                assert!(outer_args.is_empty());
+                match outer_callee.as_ref() {
+                    Expr::Member { target, field } => {
+                        assert_eq!(field, "c");
+                        match target.as_ref() {
+                            Expr::Call { callee: inner_callee, args: inner_args } => {
+                                assert!(inner_args.is_empty());
+                                match inner_callee.as_ref() {
+                                    Expr::Member { target: inner_target, field: inner_field } => {
+                                        assert!(matches!(**inner_target, Expr::Identifier(ref id) if id == "a"));
+                                        assert_eq!(inner_field, "b");
+                                    }
+                                    _ => panic!("Expected Member callee, got {:?}", inner_callee),
+                                }
+                            }
+                            _ => panic!("Expected inner Call, got {:?}", target),
+                        }
+                    }
+                    _ => panic!("Expected Member callee, got {:?}", outer_callee),
+                }
+            }
+            _ => panic!("Expected call expression, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_call_result_is_callable() {
+        // get_fn()() -- the callee of the outer call is itself a Call.
+        run_parser_test("get_fn()();", 1, 0);
+    }
+
+    #[test]
+    fn test_compound_assignment_parsing() {
+        let tests = vec![
+            ("x += 1;", BinaryOperator::Plus),
+            ("x -= 1;", BinaryOperator::Minus),
+            ("x *= 2;", BinaryOperator::Multiply),
+            ("x /= 2;", BinaryOperator::Divide),
+            ("x %= 2;", BinaryOperator::Modulo),
+        ];
+        for (input, expected_op) in tests {
+            let program = run_parser_test(input, 1, 0);
+            match &program.statements[0] {
+                Statement::Assignment { target, value_expr } => {
+                    assert_eq!(*target, Expr::Identifier("x".to_string()));
+                    match value_expr {
+                        Expr::BinaryOp { left, op, right } => {
+                            assert_eq!(**left, Expr::Identifier("x".to_string()));
+                            assert_eq!(*op, expected_op);
+                            assert!(matches!(**right, Expr::Integer(_)));
+                        }
+                        _ => panic!("Expected desugared BinaryOp for input: {}", input),
+                    }
+                }
+                _ => panic!("Expected assignment for input: {}", input),
+            }
+        }
+    }
+
+    #[test]
+    fn test_compound_index_assignment_parsing() {
+        let program = run_parser_test("arr[0] += 5;", 1, 0);
+        match &program.statements[0] {
+            Statement::Assignment { target, value_expr } => {
+                assert!(matches!(target, Expr::Index { .. }));
+                assert!(matches!(value_expr, Expr::BinaryOp { op: BinaryOperator::Plus, .. }));
+            }
+            _ => panic!("Expected assignment, got {:?}", program.statements[0]),
+        }
+    }
+
+    #[test]
+    fn test_assignment_to_non_lvalue_is_an_error() {
+        run_parser_test("foo() = 5;", 0, 1);
+    }
+
     #[test]
     fn test_sequence_of_statements_mixed_semicolons() {
         let input = r#"
@@ -1130,6 +2265,48 @@ mod tests {
         run_parser_test(";;;", 0, 0); // Each semicolon is an empty statement, not added to program.
     }
 
+    #[test]
+    fn test_statement_spans_track_line_numbers() {
+        let program = run_parser_test("let x = 1;\nlet y = 2;", 2, 0);
+        assert_eq!(program.statement_spans.len(), 2);
+        assert_eq!(program.statement_spans[0].start.line, 1);
+        assert_eq!(program.statement_spans[1].start.line, 2);
+    }
+
+    #[test]
+    fn test_repl_mode_captures_trailing_expression_as_result() {
+        let lexer = Lexer::new("let x = 1;\n x + 2");
+        let mut parser = Parser::new_repl(lexer);
+        let program = parser.parse_program().expect("expected successful parse");
+        assert_eq!(program.statements.len(), 1); // just the `let x = 1;`
+        assert_eq!(
+            program.result,
+            Some(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier("x".to_string())),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::Integer(2)),
+            })
+        );
+    }
+
+    #[test]
+    fn test_repl_mode_trailing_semicolon_suppresses_result() {
+        let lexer = Lexer::new("1 + 2;");
+        let mut parser = Parser::new_repl(lexer);
+        let program = parser.parse_program().expect("expected successful parse");
+        assert_eq!(program.statements.len(), 1);
+        assert_eq!(program.result, None);
+    }
+
+    #[test]
+    fn test_non_repl_mode_never_sets_result() {
+        let lexer = Lexer::new("1 + 2");
+        let mut parser = Parser::new(lexer);
+        let program = parser.parse_program().expect("expected successful parse");
+        assert_eq!(program.statements.len(), 1);
+        assert_eq!(program.result, None);
+    }
+
     #[test]
     fn test_statements_in_block_mixed_semicolons() {
         let input = r#"
@@ -1168,9 +2345,19 @@ mod tests {
     #[test]
     fn test_error_unclosed_parenthesis_in_expression() {
         let input = "let x = (5 + 2"; // No semicolon, but error is unclosed '('
-        run_parser_test(input, 0, 1); 
+        run_parser_test(input, 0, 1);
     }
-    
+
+    #[test]
+    fn test_unclosed_nested_delimiters_report_one_error() {
+        // `foo(`'s unclosed call paren makes the stray ';' unparseable,
+        // which would otherwise also blow up the outer `print(` and cascade
+        // into the dangling `let y = 2`. Only the innermost unclosed '('
+        // should be reported.
+        let input = "print(foo(; let y = 2";
+        run_parser_test(input, 0, 1);
+    }
+
     #[test]
     fn test_error_if_missing_condition_parentheses() {
         // This test is no longer relevant as parentheses are not expected.
@@ -1205,7 +2392,112 @@ mod tests {
     fn test_error_unexpected_token_in_statement() {
         // This test might change slightly if `5 + ;` becomes `5+` then an empty statement.
         // However, `+` expecting an operand is the primary error.
-        let input = "let x = 5 +"; 
+        let input = "let x = 5 +";
         run_parser_test(input, 1, 1); // Let statement, error in expression.
     }
+
+    #[test]
+    fn test_missing_semicolon_is_anchored_at_end_of_previous_statement() {
+        // `)` can't continue `let x = 100` and can't start a statement
+        // either, so this is the "genuinely ambiguous" case the diagnostic
+        // targets -- not the newline-separated case, which already parses
+        // cleanly (see test_sequence_of_statements_mixed_semicolons).
+        let input = "let x = 100)";
+        let mut parser = Parser::new(Lexer::new(input));
+        let result = parser.parse_program();
+        let errors = result.expect_err("expected a MissingSemicolon error");
+        let missing = errors.iter().find(|e| matches!(e, ParseError::MissingSemicolon { .. }));
+        let (position, suggestion) = match missing.expect("expected a MissingSemicolon error") {
+            ParseError::MissingSemicolon { position, suggestion } => (*position, suggestion.clone()),
+            _ => unreachable!(),
+        };
+        // "100" ends at column 12 (1-indexed, right after the last '0').
+        assert_eq!(position, Position { line: 1, column: 12 });
+        assert_eq!(suggestion, ";");
+    }
+
+    #[test]
+    fn test_missing_semicolon_suggestion_round_trips_to_valid_source() {
+        let input = "let x = 100)";
+        let mut parser = Parser::new(Lexer::new(input));
+        let errors = parser.parse_program().expect_err("expected a MissingSemicolon error");
+        let (position, suggestion) = errors
+            .iter()
+            .find_map(|e| match e {
+                ParseError::MissingSemicolon { position, suggestion } => Some((*position, suggestion.clone())),
+                _ => None,
+            })
+            .expect("expected a MissingSemicolon error");
+        // Splicing `suggestion` in at the byte matching `position` (end of
+        // line 1, column 12) turns the input into two valid statements.
+        let insert_at = input.find(')').unwrap();
+        let mut fixed = input.to_string();
+        fixed.insert_str(insert_at, &suggestion);
+        assert_eq!(fixed, "let x = 100;)");
+        assert_eq!(position.column, insert_at + 1);
+    }
+
+    #[test]
+    fn test_missing_semicolon_not_raised_for_newline_separated_statements() {
+        // No semicolon, but `println` is a legal statement-starter, so this
+        // is NOT the ambiguous case -- it already parses cleanly today.
+        let input = "let x = 100\nprintln(x)";
+        run_parser_test(input, 2, 0);
+    }
+
+    #[test]
+    fn test_if_missing_braces_recovers_one_statement_body() {
+        let input = "if x < 10 print(x)";
+        let mut parser = Parser::new(Lexer::new(input));
+        let statement = parser.parse_statement().expect("recovery should still produce a Statement::If");
+        match statement {
+            Statement::If { then_block, else_if_blocks, else_block, .. } => {
+                assert_eq!(then_block.statements.len(), 1, "recovered body should hold exactly the one statement that follows");
+                assert!(matches!(&then_block.statements[0], Statement::Print { .. }));
+                assert!(else_if_blocks.is_empty());
+                assert!(else_block.is_none());
+            }
+            other => panic!("expected Statement::If, got {:?}", other),
+        }
+        assert_eq!(parser.errors.len(), 1);
+        assert!(matches!(
+            &parser.errors[0],
+            ParseError::MissingBraces { construct, .. } if *construct == "if"
+        ));
+    }
+
+    #[test]
+    fn test_for_missing_braces_recovers_one_statement_body() {
+        let input = "for ;; i + 1 print(i)";
+        let mut parser = Parser::new(Lexer::new(input));
+        let statement = parser.parse_statement().expect("recovery should still produce a Statement::For");
+        match statement {
+            Statement::For { initializer, condition, increment, body_block } => {
+                assert!(initializer.is_none());
+                assert!(condition.is_none());
+                assert!(increment.is_some());
+                assert_eq!(body_block.statements.len(), 1, "recovered body should hold exactly the one statement that follows");
+                assert!(matches!(&body_block.statements[0], Statement::Print { .. }));
+            }
+            other => panic!("expected Statement::For, got {:?}", other),
+        }
+        assert_eq!(parser.errors.len(), 1);
+        assert!(matches!(
+            &parser.errors[0],
+            ParseError::MissingBraces { construct, .. } if *construct == "for"
+        ));
+    }
+
+    #[test]
+    fn test_full_optimization_drops_dead_statements() {
+        // `5 + 2;` is pure and its value is discarded, so `Full` drops it
+        // entirely, while `let x = 1;` and `print(x);` (never dead, per
+        // `optimize::tests::full_keeps_assignments_and_let_decls_even_when_unused`)
+        // survive -- 3 parsed statements become 2 optimized ones.
+        run_parser_test_optimized(
+            "let x = 1; 5 + 2; print(x);",
+            crate::optimize::OptimizationLevel::Full,
+            2,
+        );
+    }
 }
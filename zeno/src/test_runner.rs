@@ -0,0 +1,259 @@
+//! Test runner backing the `zeno test` subcommand. Scans a directory for
+//! `.zeno` files carrying leading `// run-pass` / `// compile-fail` /
+//! `// expected-output: ...` annotation comments, drives each one through
+//! the lex -> parse -> generate -> rustc -> run pipeline in its own temp
+//! directory, and prints a per-test `ok`/`FAILED` summary.
+
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+
+use zeno::generator;
+use zeno::lexer::Lexer;
+use zeno::parser::{ParseError, Parser};
+
+/// What a test file expects of the pipeline, parsed from its leading
+/// comment block.
+#[derive(Debug, Clone)]
+enum Expectation {
+    /// The default when no `compile-fail` directive is present: the
+    /// program must lex, parse, generate, compile, and run successfully,
+    /// with stdout matching the concatenated `expected-output` lines.
+    RunPass { expected_output: String },
+    /// `// compile-fail`: parsing or `rustc` must fail. An optional
+    /// substring, from `// compile-fail: <substring>`, must appear
+    /// somewhere in the resulting error text.
+    CompileFail { expected_substring: Option<String> },
+}
+
+struct TestOutcome {
+    name: String,
+    passed: bool,
+    detail: Option<String>,
+}
+
+/// Recursively finds `.zeno` files under `dir`, compiles/runs (or
+/// compile-fail-checks) each one per its annotations, and prints a
+/// per-test and summary report. Returns `(passed, failed)` counts.
+pub fn run(dir: &Path, jobs: usize) -> io::Result<(usize, usize)> {
+    let mut files = Vec::new();
+    find_zeno_files(dir, &mut files)?;
+    files.sort();
+
+    let queue: Mutex<VecDeque<(usize, PathBuf)>> =
+        Mutex::new(files.into_iter().enumerate().collect());
+    let results: Mutex<Vec<TestOutcome>> = Mutex::new(Vec::new());
+
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            scope.spawn(|| loop {
+                let next = queue.lock().unwrap().pop_front();
+                let Some((index, path)) = next else {
+                    break;
+                };
+                let outcome = run_test(&path, dir, index);
+                results.lock().unwrap().push(outcome);
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut passed = 0;
+    let mut failed = 0;
+    for outcome in &results {
+        if outcome.passed {
+            passed += 1;
+            println!("test {} ... ok", outcome.name);
+        } else {
+            failed += 1;
+            println!("test {} ... FAILED", outcome.name);
+        }
+    }
+    for outcome in &results {
+        if let Some(detail) = &outcome.detail {
+            println!("\n---- {} ----\n{}", outcome.name, detail);
+        }
+    }
+    println!(
+        "\ntest result: {}. {passed} passed; {failed} failed",
+        if failed == 0 { "ok" } else { "FAILED" },
+    );
+
+    Ok((passed, failed))
+}
+
+fn find_zeno_files(dir: &Path, out: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            find_zeno_files(&path, out)?;
+        } else if path.extension().and_then(|ext| ext.to_str()) == Some("zeno") {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn run_test(path: &Path, base_dir: &Path, index: usize) -> TestOutcome {
+    let name = path
+        .strip_prefix(base_dir)
+        .unwrap_or(path)
+        .display()
+        .to_string();
+    let temp_dir = std::env::temp_dir().join(format!("zeno-test-{}-{index}", std::process::id()));
+
+    let result = run_test_inner(path, &temp_dir);
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    match result {
+        Ok(()) => TestOutcome { name, passed: true, detail: None },
+        Err(detail) => TestOutcome { name, passed: false, detail: Some(detail) },
+    }
+}
+
+fn run_test_inner(path: &Path, temp_dir: &Path) -> Result<(), String> {
+    let source = fs::read_to_string(path).map_err(|e| format!("failed to read source: {e}"))?;
+    let expectation = parse_annotations(&source);
+
+    fs::create_dir_all(temp_dir).map_err(|e| format!("failed to create temp dir: {e}"))?;
+    let rust_path = temp_dir.join("test.rs");
+    let exe_path = temp_dir.join("test_exe");
+
+    let lexer = Lexer::new(&source);
+    let mut parser = Parser::new(lexer);
+    let parse_result = parser.parse_program();
+
+    match expectation {
+        Expectation::CompileFail { expected_substring } => match parse_result {
+            Err(errors) => check_substring(&join_errors(&errors), expected_substring.as_deref()),
+            Ok(ast) => match generator::generate(&ast) {
+                Err(e) => check_substring(&e.to_string(), expected_substring.as_deref()),
+                Ok(rust_code) => {
+                    fs::write(&rust_path, &rust_code).map_err(|e| e.to_string())?;
+                    let output = Command::new("rustc")
+                        .arg(&rust_path)
+                        .arg("-o")
+                        .arg(&exe_path)
+                        .output()
+                        .map_err(|e| format!("failed to invoke rustc: {e}"))?;
+                    if output.status.success() {
+                        Err("expected compile-fail, but rustc succeeded".to_string())
+                    } else {
+                        check_substring(&String::from_utf8_lossy(&output.stderr), expected_substring.as_deref())
+                    }
+                }
+            },
+        },
+        Expectation::RunPass { expected_output } => {
+            let ast = parse_result.map_err(|errors| format!("parsing failed:\n{}", join_errors(&errors)))?;
+            let rust_code = generator::generate(&ast).map_err(|e| format!("code generation failed: {e}"))?;
+            fs::write(&rust_path, &rust_code).map_err(|e| e.to_string())?;
+
+            let compile_output = Command::new("rustc")
+                .arg(&rust_path)
+                .arg("-o")
+                .arg(&exe_path)
+                .output()
+                .map_err(|e| format!("failed to invoke rustc: {e}"))?;
+            if !compile_output.status.success() {
+                return Err(format!("rustc failed:\n{}", String::from_utf8_lossy(&compile_output.stderr)));
+            }
+
+            let run_output = Command::new(&exe_path)
+                .output()
+                .map_err(|e| format!("failed to run executable: {e}"))?;
+            let actual = String::from_utf8_lossy(&run_output.stdout).trim_end().to_string();
+            let expected = expected_output.trim_end().to_string();
+            if actual == expected {
+                Ok(())
+            } else {
+                Err(unified_diff(&expected, &actual))
+            }
+        }
+    }
+}
+
+/// Scans a source file's leading comment block for `// run-pass`,
+/// `// compile-fail[: substring]`, and `// expected-output: <line>`
+/// directives. Stops at the first non-comment, non-blank line, since
+/// annotations only live in the file's header.
+fn parse_annotations(source: &str) -> Expectation {
+    let mut compile_fail = false;
+    let mut compile_fail_substring = None;
+    let mut expected_lines = Vec::new();
+
+    for line in source.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line == "// run-pass" {
+            continue;
+        } else if let Some(rest) = line.strip_prefix("// compile-fail") {
+            compile_fail = true;
+            if let Some(substring) = rest.strip_prefix(':') {
+                compile_fail_substring = Some(substring.trim().to_string());
+            }
+        } else if let Some(rest) = line.strip_prefix("// expected-output:") {
+            expected_lines.push(rest.trim().to_string());
+        } else if line.starts_with("//") {
+            continue;
+        } else {
+            break;
+        }
+    }
+
+    if compile_fail {
+        Expectation::CompileFail { expected_substring: compile_fail_substring }
+    } else {
+        Expectation::RunPass { expected_output: expected_lines.join("\n") }
+    }
+}
+
+/// Renders a parser's structured errors the way the old `Vec<String>` used
+/// to print: one per line, in order.
+fn join_errors(errors: &[ParseError]) -> String {
+    errors.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("\n")
+}
+
+fn check_substring(text: &str, expected_substring: Option<&str>) -> Result<(), String> {
+    match expected_substring {
+        Some(sub) if !text.contains(sub) => {
+            Err(format!("error text did not contain expected substring '{sub}':\n{text}"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// A minimal line-based diff, good enough to show exactly which lines of
+/// expected output didn't match.
+fn unified_diff(expected: &str, actual: &str) -> String {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let mut out = String::new();
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(e), Some(a)) if e == a => {
+                let _ = writeln!(out, "  {e}");
+            }
+            (Some(e), Some(a)) => {
+                let _ = writeln!(out, "- {e}");
+                let _ = writeln!(out, "+ {a}");
+            }
+            (Some(e), None) => {
+                let _ = writeln!(out, "- {e}");
+            }
+            (None, Some(a)) => {
+                let _ = writeln!(out, "+ {a}");
+            }
+            (None, None) => unreachable!(),
+        }
+    }
+    out
+}
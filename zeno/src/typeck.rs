@@ -0,0 +1,469 @@
+use crate::ast::*;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Type {
+    Int,
+    Float,
+    Bool,
+    String,
+    Array(Box<Type>),
+    Var(u32),
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Int => write!(f, "int"),
+            Type::Float => write!(f, "float"),
+            Type::Bool => write!(f, "bool"),
+            Type::String => write!(f, "string"),
+            Type::Array(elem) => write!(f, "{}[]", elem),
+            Type::Var(id) => write!(f, "?{}", id),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeError {
+    pub message: String,
+    pub expr: Expr,
+}
+
+impl fmt::Display for TypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Type Error: {} (in `{:?}`)", self.message, self.expr)
+    }
+}
+
+impl std::error::Error for TypeError {}
+
+/// A union-find substitution of type variables to concrete (or still-open)
+/// types, as used by Algorithm W.
+#[derive(Debug, Default)]
+struct Substitution {
+    bindings: HashMap<u32, Type>,
+}
+
+impl Substitution {
+    fn resolve(&self, ty: &Type) -> Type {
+        match ty {
+            Type::Var(id) => match self.bindings.get(id) {
+                Some(bound) if bound != ty => self.resolve(bound),
+                _ => ty.clone(),
+            },
+            other => other.clone(),
+        }
+    }
+
+    fn bind(&mut self, id: u32, ty: Type) {
+        self.bindings.insert(id, ty);
+    }
+}
+
+/// The result of a successful type-checking pass: every resolvable
+/// expression/let-binding mapped to its concrete type.
+#[derive(Debug)]
+pub struct TypeckResult {
+    pub bindings: HashMap<String, Type>,
+}
+
+pub struct TypeChecker {
+    subst: Substitution,
+    next_var: u32,
+    env: Vec<HashMap<String, Type>>,
+}
+
+impl TypeChecker {
+    pub fn new() -> Self {
+        TypeChecker {
+            subst: Substitution::default(),
+            next_var: 0,
+            env: vec![HashMap::new()],
+        }
+    }
+
+    fn fresh_var(&mut self) -> Type {
+        let id = self.next_var;
+        self.next_var += 1;
+        Type::Var(id)
+    }
+
+    fn push_scope(&mut self) {
+        self.env.push(HashMap::new());
+    }
+
+    fn pop_scope(&mut self) {
+        self.env.pop();
+    }
+
+    fn define(&mut self, name: &str, ty: Type) {
+        self.env.last_mut().unwrap().insert(name.to_string(), ty);
+    }
+
+    fn lookup(&self, name: &str) -> Option<Type> {
+        for scope in self.env.iter().rev() {
+            if let Some(ty) = scope.get(name) {
+                return Some(ty.clone());
+            }
+        }
+        None
+    }
+
+    fn unify(&mut self, a: &Type, b: &Type, expr: &Expr) -> Result<Type, TypeError> {
+        let a = self.subst.resolve(a);
+        let b = self.subst.resolve(b);
+        match (&a, &b) {
+            (Type::Var(id), other) | (other, Type::Var(id)) => {
+                self.subst.bind(*id, other.clone());
+                Ok(other.clone())
+            }
+            (Type::Array(a_elem), Type::Array(b_elem)) => {
+                let elem = self.unify(a_elem, b_elem, expr)?;
+                Ok(Type::Array(Box::new(elem)))
+            }
+            (x, y) if x == y => Ok(x.clone()),
+            (x, y) => Err(TypeError {
+                message: format!("cannot unify `{}` with `{}`", x, y),
+                expr: expr.clone(),
+            }),
+        }
+    }
+
+    fn annotation_type(name: &str) -> Option<Type> {
+        if let Some(element_name) = name.strip_suffix("[]") {
+            return Self::annotation_type(element_name).map(|ty| Type::Array(Box::new(ty)));
+        }
+        match name {
+            "int" => Some(Type::Int),
+            "float" => Some(Type::Float),
+            "bool" => Some(Type::Bool),
+            "string" => Some(Type::String),
+            _ => None,
+        }
+    }
+
+    pub fn check_program(&mut self, program: &Program) -> Result<TypeckResult, TypeError> {
+        for statement in &program.statements {
+            self.check_statement(statement)?;
+        }
+        let mut bindings = HashMap::new();
+        for scope in &self.env {
+            for (name, ty) in scope {
+                bindings.insert(name.clone(), self.subst.resolve(ty));
+            }
+        }
+        Ok(TypeckResult { bindings })
+    }
+
+    fn check_block(&mut self, block: &Block) -> Result<(), TypeError> {
+        self.push_scope();
+        let result = (|| {
+            for statement in &block.statements {
+                self.check_statement(statement)?;
+            }
+            // Checked for internal consistency even though only a function
+            // body's `Block::result` is ever read back out (see
+            // `Statement::FnDecl` below) -- an `if`/`while`/`for`/`loop` body
+            // can still syntactically end in a bare expression, and leaving
+            // it unchecked would be a silent hole.
+            if let Some(result) = &block.result {
+                self.infer(result)?;
+            }
+            Ok(())
+        })();
+        self.pop_scope();
+        result
+    }
+
+    fn check_statement(&mut self, statement: &Statement) -> Result<(), TypeError> {
+        match statement {
+            Statement::LetDecl { name, type_ann, value_expr, .. } => {
+                let value_ty = self.infer(value_expr)?;
+                let declared = match type_ann.as_deref().and_then(Self::annotation_type) {
+                    Some(ty) => self.unify(&ty, &value_ty, value_expr)?,
+                    None => value_ty,
+                };
+                self.define(name, declared);
+                Ok(())
+            }
+            Statement::Assignment { target, value_expr } => {
+                let value_ty = self.infer(value_expr)?;
+                match target {
+                    Expr::Identifier(name) => {
+                        let existing = self
+                            .lookup(name)
+                            .unwrap_or_else(|| self.fresh_var());
+                        self.unify(&existing, &value_ty, value_expr)?;
+                    }
+                    Expr::Index { .. } => {
+                        let elem_ty = self.infer(target)?;
+                        self.unify(&elem_ty, &value_ty, value_expr)?;
+                    }
+                    _ => {
+                        return Err(TypeError {
+                            message: format!("Cannot assign to '{:?}'", target),
+                            expr: target.clone(),
+                        })
+                    }
+                }
+                Ok(())
+            }
+            Statement::ExprStatement { expr } => {
+                self.infer(expr)?;
+                Ok(())
+            }
+            Statement::If { condition, then_block, else_if_blocks, else_block } => {
+                let cond_ty = self.infer(condition)?;
+                self.unify(&cond_ty, &Type::Bool, condition)?;
+                self.check_block(then_block)?;
+                for (cond, block) in else_if_blocks {
+                    let ty = self.infer(cond)?;
+                    self.unify(&ty, &Type::Bool, cond)?;
+                    self.check_block(block)?;
+                }
+                if let Some(block) = else_block {
+                    self.check_block(block)?;
+                }
+                Ok(())
+            }
+            Statement::While { condition, body_block } => {
+                let cond_ty = self.infer(condition)?;
+                self.unify(&cond_ty, &Type::Bool, condition)?;
+                self.check_block(body_block)
+            }
+            Statement::Loop { body_block } => self.check_block(body_block),
+            Statement::For { initializer, condition, increment, body_block } => {
+                self.push_scope();
+                if let Some(init) = initializer {
+                    self.check_statement(init)?;
+                }
+                if let Some(cond) = condition {
+                    let ty = self.infer(cond)?;
+                    self.unify(&ty, &Type::Bool, cond)?;
+                }
+                if let Some(inc) = increment {
+                    self.check_statement(inc)?;
+                }
+                for statement in &body_block.statements {
+                    self.check_statement(statement)?;
+                }
+                self.pop_scope();
+                Ok(())
+            }
+            Statement::Print { expr, .. } => {
+                self.infer(expr)?;
+                Ok(())
+            }
+            Statement::Break | Statement::Continue => Ok(()),
+            Statement::FnDecl { params, body, .. } => {
+                self.push_scope();
+                for (name, type_ann) in params {
+                    let ty = Self::annotation_type(type_ann).unwrap_or_else(|| self.fresh_var());
+                    self.define(name, ty);
+                }
+                for statement in &body.statements {
+                    self.check_statement(statement)?;
+                }
+                // An implicit-return trailing expression is still checked for
+                // internal consistency, same as an explicit `return`'s expr
+                // below -- neither is unified against `return_type` since
+                // functions aren't registered as typed callables yet.
+                if let Some(result) = &body.result {
+                    self.infer(result)?;
+                }
+                self.pop_scope();
+                Ok(())
+            }
+            Statement::Return { expr } => {
+                if let Some(expr) = expr {
+                    self.infer(expr)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn infer(&mut self, expr: &Expr) -> Result<Type, TypeError> {
+        match expr {
+            Expr::Integer(_) => Ok(Type::Int),
+            Expr::Float(_) => Ok(Type::Float),
+            Expr::Boolean(_) => Ok(Type::Bool),
+            Expr::StringLiteral(_) => Ok(Type::String),
+            Expr::Identifier(name) => Ok(self.lookup(name).unwrap_or_else(|| self.fresh_var())),
+            Expr::UnaryOp { op, expr: inner } => {
+                let inner_ty = self.infer(inner)?;
+                match op {
+                    UnaryOperator::Not => self.unify(&inner_ty, &Type::Bool, inner),
+                    UnaryOperator::Negate => Ok(inner_ty),
+                }
+            }
+            Expr::BinaryOp { left, op, right } => {
+                let left_ty = self.infer(left)?;
+                let right_ty = self.infer(right)?;
+                match op {
+                    BinaryOperator::Plus
+                    | BinaryOperator::Minus
+                    | BinaryOperator::Multiply
+                    | BinaryOperator::Divide
+                    | BinaryOperator::Modulo => self.unify(&left_ty, &right_ty, expr),
+                    BinaryOperator::Eq
+                    | BinaryOperator::NotEq
+                    | BinaryOperator::Lt
+                    | BinaryOperator::Lte
+                    | BinaryOperator::Gt
+                    | BinaryOperator::Gte => {
+                        self.unify(&left_ty, &right_ty, expr)?;
+                        Ok(Type::Bool)
+                    }
+                    BinaryOperator::And | BinaryOperator::Or => {
+                        self.unify(&left_ty, &Type::Bool, left)?;
+                        self.unify(&right_ty, &Type::Bool, right)?;
+                        Ok(Type::Bool)
+                    }
+                }
+            }
+            Expr::Call { args, .. } => {
+                // Builtins aren't declared anywhere yet, so calls resolve to
+                // a fresh type variable; arguments are still checked so that
+                // inconsistencies inside them are caught.
+                for arg in args {
+                    self.infer(arg)?;
+                }
+                Ok(self.fresh_var())
+            }
+            // No struct/object types exist to look `field` up on, so (like
+            // Call above) this only checks `target` for internal errors.
+            Expr::Member { target, field: _ } => {
+                self.infer(target)?;
+                Ok(self.fresh_var())
+            }
+            Expr::ArrayLiteral(elements) => {
+                let elem_ty = self.fresh_var();
+                for element in elements {
+                    let this_ty = self.infer(element)?;
+                    self.unify(&elem_ty, &this_ty, element)?;
+                }
+                Ok(Type::Array(Box::new(self.subst.resolve(&elem_ty))))
+            }
+            // There's no Type::Map -- map values are heterogeneous by design,
+            // so (like Call and OperatorFn below) this only checks the value
+            // expressions for internal errors and punts on a structural type.
+            Expr::Map(entries) => {
+                for (_, value) in entries {
+                    self.infer(value)?;
+                }
+                Ok(self.fresh_var())
+            }
+            // Array indexing only; map indexing (`m["key"]`) isn't modeled
+            // here since there's no Type::Map to dispatch on -- it type-checks
+            // fine in practice because `target_ty` is usually a fresh var.
+            Expr::Index { target, index } => {
+                let index_ty = self.infer(index)?;
+                self.unify(&index_ty, &Type::Int, index)?;
+                let target_ty = self.infer(target)?;
+                let elem_ty = self.fresh_var();
+                match self.unify(&target_ty, &Type::Array(Box::new(elem_ty.clone())), target)? {
+                    Type::Array(inner) => Ok(*inner),
+                    _ => Ok(elem_ty),
+                }
+            }
+            // Not invocable yet (Expr::Call's callee is a bare name), so
+            // there's no function type to model -- same treatment as Call.
+            Expr::OperatorFn(_) => Ok(self.fresh_var()),
+        }
+    }
+}
+
+impl Default for TypeChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Type-check `program`, returning the resolved type of every binding.
+pub fn check(program: &Program) -> Result<TypeckResult, TypeError> {
+    TypeChecker::new().check_program(program)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Lexer;
+    use crate::parser::Parser;
+
+    fn typecheck(input: &str) -> Result<TypeckResult, TypeError> {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        let program = parser.parse_program().expect("parse failed");
+        check(&program)
+    }
+
+    #[test]
+    fn test_infers_let_binding_type() {
+        let result = typecheck("let x = 5;").unwrap();
+        assert_eq!(result.bindings.get("x"), Some(&Type::Int));
+    }
+
+    #[test]
+    fn test_annotation_matches_value() {
+        let result = typecheck("let x: float = 1.5;").unwrap();
+        assert_eq!(result.bindings.get("x"), Some(&Type::Float));
+    }
+
+    #[test]
+    fn test_reassigning_with_wrong_type_errors() {
+        let err = typecheck("let x = 1; x = \"s\";").unwrap_err();
+        assert!(err.message.contains("cannot unify"));
+    }
+
+    #[test]
+    fn test_condition_must_be_bool() {
+        let err = typecheck("if 1 { print(1); }").unwrap_err();
+        assert!(err.message.contains("cannot unify"));
+    }
+
+    #[test]
+    fn test_comparison_yields_bool() {
+        let result = typecheck("let ok = 1 < 2;").unwrap();
+        assert_eq!(result.bindings.get("ok"), Some(&Type::Bool));
+    }
+
+    #[test]
+    fn test_annotation_mismatch_errors() {
+        let err = typecheck("let x: int = \"no\";").unwrap_err();
+        assert!(err.message.contains("cannot unify"));
+    }
+
+    #[test]
+    fn test_array_literal_and_index_types() {
+        let result = typecheck("let xs = [1, 2, 3]; let y = xs[0];").unwrap();
+        assert_eq!(result.bindings.get("xs"), Some(&Type::Array(Box::new(Type::Int))));
+        assert_eq!(result.bindings.get("y"), Some(&Type::Int));
+    }
+
+    #[test]
+    fn test_array_literal_mismatched_elements_errors() {
+        let err = typecheck("let xs = [1, \"two\"];").unwrap_err();
+        assert!(err.message.contains("cannot unify"));
+    }
+
+    #[test]
+    fn test_index_assignment_type_mismatch_errors() {
+        let err = typecheck("let xs = [1, 2]; xs[0] = \"no\";").unwrap_err();
+        assert!(err.message.contains("cannot unify"));
+    }
+
+    #[test]
+    fn test_fn_params_are_typed_in_body() {
+        let err = typecheck("fn add(a: int, b: int): int { return a + \"s\"; }").unwrap_err();
+        assert!(err.message.contains("cannot unify"));
+    }
+
+    #[test]
+    fn test_fn_implicit_return_expression_is_checked() {
+        let err = typecheck("fn add(a: int, b: int): int { a + \"s\" }").unwrap_err();
+        assert!(err.message.contains("cannot unify"));
+    }
+}